@@ -0,0 +1,369 @@
+use core::fmt::*;
+
+use crate::{transform_if, types::CondOr};
+
+pub(crate) mod types {
+    #[allow(unused)]
+    use super::*;
+
+    /// See [`colored()`].
+    #[derive(Clone, Copy)]
+    pub struct Colored<T> {
+        pub(super) value: T,
+        pub(super) code: u8,
+    }
+
+    /// See [`strip_ansi()`].
+    #[derive(Clone, Copy)]
+    pub struct StripAnsi<T> {
+        pub(super) value: T,
+    }
+}
+
+use types::*;
+
+/// Places a value between an [ANSI SGR](https://en.wikipedia.org/wiki/ANSI_escape_code#SGR)
+/// escape sequence and a reset (`\x1b[0m`).
+///
+/// Consider using [`colored_if()`] to only color when output is a terminal.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::colored("hola", 31);
+/// assert_eq!(value.to_string(), "\x1b[31mhola\x1b[0m");
+/// ```
+pub fn colored<T>(value: T, code: u8) -> Colored<T> {
+    Colored { value, code }
+}
+
+/// Conditionally places a value between an ANSI escape sequence and a reset,
+/// or writes the value unchanged if `false`.
+///
+/// This is equivalent to
+/// <code>[transform_if]\(enabled, value, |value| [colored]\(value, code\)\)</code>.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::colored_if(true, "hola", 31);
+/// assert_eq!(value.to_string(), "\x1b[31mhola\x1b[0m");
+///
+/// let value = fmty::colored_if(false, "hola", 31);
+/// assert_eq!(value.to_string(), "hola");
+/// ```
+pub fn colored_if<T>(
+    enabled: bool,
+    value: T,
+    code: u8,
+) -> CondOr<Colored<T>, T> {
+    transform_if(enabled, value, |value| colored(value, code))
+}
+
+/// Makes a value bold with ANSI code 1.
+///
+/// This is equivalent to <code>[colored]\(value, 1\)</code>.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::bold("hola");
+/// assert_eq!(value.to_string(), "\x1b[1mhola\x1b[0m");
+/// ```
+pub fn bold<T>(value: T) -> Colored<T> {
+    colored(value, 1)
+}
+
+/// Makes a value dim with ANSI code 2.
+///
+/// This is equivalent to <code>[colored]\(value, 2\)</code>.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::dim("hola");
+/// assert_eq!(value.to_string(), "\x1b[2mhola\x1b[0m");
+/// ```
+pub fn dim<T>(value: T) -> Colored<T> {
+    colored(value, 2)
+}
+
+/// Makes a value italic with ANSI code 3.
+///
+/// This is equivalent to <code>[colored]\(value, 3\)</code>.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::italic("hola");
+/// assert_eq!(value.to_string(), "\x1b[3mhola\x1b[0m");
+/// ```
+pub fn italic<T>(value: T) -> Colored<T> {
+    colored(value, 3)
+}
+
+/// Underlines a value with ANSI code 4.
+///
+/// This is equivalent to <code>[colored]\(value, 4\)</code>.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::underline("hola");
+/// assert_eq!(value.to_string(), "\x1b[4mhola\x1b[0m");
+/// ```
+pub fn underline<T>(value: T) -> Colored<T> {
+    colored(value, 4)
+}
+
+/// Colors a value red with ANSI code 31.
+///
+/// This is equivalent to <code>[colored]\(value, 31\)</code>.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::red("hola");
+/// assert_eq!(value.to_string(), "\x1b[31mhola\x1b[0m");
+/// ```
+pub fn red<T>(value: T) -> Colored<T> {
+    colored(value, 31)
+}
+
+/// Colors a value green with ANSI code 32.
+///
+/// This is equivalent to <code>[colored]\(value, 32\)</code>.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::green("hola");
+/// assert_eq!(value.to_string(), "\x1b[32mhola\x1b[0m");
+/// ```
+pub fn green<T>(value: T) -> Colored<T> {
+    colored(value, 32)
+}
+
+/// Colors a value yellow with ANSI code 33.
+///
+/// This is equivalent to <code>[colored]\(value, 33\)</code>.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::yellow("hola");
+/// assert_eq!(value.to_string(), "\x1b[33mhola\x1b[0m");
+/// ```
+pub fn yellow<T>(value: T) -> Colored<T> {
+    colored(value, 33)
+}
+
+/// Colors a value blue with ANSI code 34.
+///
+/// This is equivalent to <code>[colored]\(value, 34\)</code>.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::blue("hola");
+/// assert_eq!(value.to_string(), "\x1b[34mhola\x1b[0m");
+/// ```
+pub fn blue<T>(value: T) -> Colored<T> {
+    colored(value, 34)
+}
+
+/// Colors a value magenta with ANSI code 35.
+///
+/// This is equivalent to <code>[colored]\(value, 35\)</code>.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::magenta("hola");
+/// assert_eq!(value.to_string(), "\x1b[35mhola\x1b[0m");
+/// ```
+pub fn magenta<T>(value: T) -> Colored<T> {
+    colored(value, 35)
+}
+
+/// Colors a value cyan with ANSI code 36.
+///
+/// This is equivalent to <code>[colored]\(value, 36\)</code>.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::cyan("hola");
+/// assert_eq!(value.to_string(), "\x1b[36mhola\x1b[0m");
+/// ```
+pub fn cyan<T>(value: T) -> Colored<T> {
+    colored(value, 36)
+}
+
+/// Removes ANSI [CSI](https://en.wikipedia.org/wiki/ANSI_escape_code#CSI_(Control_Sequence_Introducer)_sequences)
+/// sequences, such as those written by [`colored()`].
+///
+/// A sequence starts with `\x1b[` and ends with a byte in `@`–`~`. Sequences
+/// may be split across multiple writes by the inner value. An unterminated
+/// sequence at the end is removed.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::strip_ansi(fmty::red("hola"));
+/// assert_eq!(value.to_string(), "hola");
+/// ```
+pub fn strip_ansi<T>(value: T) -> StripAnsi<T> {
+    StripAnsi { value }
+}
+
+/// Where [`StripAnsiWriter`] is within an escape sequence.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum State {
+    Text,
+    Escape,
+    Csi,
+}
+
+/// Forwards writes to `inner` without ANSI CSI sequences.
+pub(crate) struct StripAnsiWriter<W> {
+    inner: W,
+    state: State,
+}
+
+impl<W: Write> StripAnsiWriter<W> {
+    pub(crate) fn new(inner: W) -> Self {
+        Self { inner, state: State::Text }
+    }
+
+    /// Writes a trailing `\x1b` that did not start a sequence.
+    pub(crate) fn finish(mut self) -> Result {
+        if self.state == State::Escape {
+            self.inner.write_char('\x1b')?;
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for StripAnsiWriter<W> {
+    fn write_str(&mut self, s: &str) -> Result {
+        let bytes = s.as_bytes();
+        let mut start = 0;
+
+        for (i, &b) in bytes.iter().enumerate() {
+            match self.state {
+                State::Text => {
+                    if b == 0x1b {
+                        self.inner.write_str(&s[start..i])?;
+                        self.state = State::Escape;
+                    }
+                }
+                State::Escape => {
+                    if b == b'[' {
+                        self.state = State::Csi;
+                    } else {
+                        // Not a CSI sequence, so keep the escape.
+                        self.inner.write_char('\x1b')?;
+
+                        if b != 0x1b {
+                            self.state = State::Text;
+                            start = i;
+                            continue;
+                        }
+                    }
+                }
+                State::Csi => {
+                    if (b'@'..=b'~').contains(&b) {
+                        self.state = State::Text;
+                        start = i + 1;
+                    }
+                }
+            }
+
+            if self.state != State::Text {
+                start = i + 1;
+            }
+        }
+
+        if self.state == State::Text {
+            self.inner.write_str(&s[start..])?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Debug> Debug for Colored<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "\x1b[{}m{:?}\x1b[0m", self.code, self.value)
+    }
+}
+
+impl<T: Display> Display for Colored<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "\x1b[{}m{}\x1b[0m", self.code, self.value)
+    }
+}
+
+impl<T: Display> Display for StripAnsi<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let mut writer = StripAnsiWriter::new(f);
+        write!(writer, "{}", self.value)?;
+        writer.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes() {
+        assert_eq!(colored("", 0).to_string(), "\x1b[0m\x1b[0m");
+        assert_eq!(colored(42, 92).to_string(), "\x1b[92m42\x1b[0m");
+        assert_eq!(format!("{:?}", colored("a", 1)), "\x1b[1m\"a\"\x1b[0m");
+        assert_eq!(
+            bold(red("!")).to_string(),
+            "\x1b[1m\x1b[31m!\x1b[0m\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn disabled() {
+        assert_eq!(colored_if(true, "a", 32).to_string(), "\x1b[32ma\x1b[0m");
+        assert_eq!(colored_if(false, "a", 32).to_string(), "a");
+        assert_eq!(format!("{:?}", colored_if(false, "a", 32)), "\"a\"");
+    }
+
+    #[test]
+    fn strip() {
+        assert_eq!(strip_ansi("").to_string(), "");
+        assert_eq!(strip_ansi("plain").to_string(), "plain");
+        assert_eq!(
+            strip_ansi("\x1b[1;31merror\x1b[0m: \x1b[4mbad\x1b[m").to_string(),
+            "error: bad",
+        );
+        assert_eq!(strip_ansi(bold(green(42))).to_string(), "42");
+        assert_eq!(strip_ansi("\x1b[0m\x1b[0m").to_string(), "");
+    }
+
+    #[test]
+    fn strip_split() {
+        let value = format_args!("{}{}{}", "a\x1b", "[3", "1mb\x1b[0m");
+        assert_eq!(strip_ansi(value).to_string(), "ab");
+
+        let value = format_args!("{}{}", "a\x1b[", "?25lb");
+        assert_eq!(strip_ansi(value).to_string(), "ab");
+    }
+
+    #[test]
+    fn strip_incomplete() {
+        assert_eq!(strip_ansi("a\x1b[31").to_string(), "a");
+        assert_eq!(strip_ansi("a\x1b").to_string(), "a\x1b");
+        assert_eq!(strip_ansi("a\x1bb").to_string(), "a\x1bb");
+        assert_eq!(
+            strip_ansi(format_args!("{}{}", "a\x1b", "b")).to_string(),
+            "a\x1bb",
+        );
+        assert_eq!(strip_ansi("\x1b\x1b[31ma").to_string(), "\x1ba");
+    }
+}
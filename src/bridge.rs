@@ -0,0 +1,121 @@
+use core::fmt::*;
+
+pub(crate) mod types {
+    #[allow(unused)]
+    use super::*;
+
+    /// See [`debug_unquoted()`].
+    #[derive(Clone, Copy)]
+    pub struct DebugUnquoted<T> {
+        pub(super) value: T,
+    }
+}
+
+use types::*;
+
+/// Writes the [`Debug`] output of a value without the quotes around strings.
+///
+/// Escapes like `\n` and `\"` are kept. If the output starts with `"`, that
+/// `"` and a `"` at the very end are removed. Otherwise, the output is written
+/// unchanged.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::debug_unquoted("line 1\nline 2");
+/// assert_eq!(value.to_string(), r"line 1\nline 2");
+///
+/// let value = fmty::debug_unquoted(Some("x"));
+/// assert_eq!(value.to_string(), r#"Some("x")"#);
+/// ```
+pub fn debug_unquoted<T>(value: T) -> DebugUnquoted<T> {
+    DebugUnquoted { value }
+}
+
+impl<T: Debug> Display for DebugUnquoted<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        struct Writer<'a, 'b> {
+            f: &'a mut Formatter<'b>,
+            started: bool,
+            quoted: bool,
+
+            // Held back until we know whether it ends the output.
+            pending_quote: bool,
+        }
+
+        impl Write for Writer<'_, '_> {
+            fn write_str(&mut self, mut s: &str) -> Result {
+                if !self.started && !s.is_empty() {
+                    self.started = true;
+                    if let Some(rest) = s.strip_prefix('"') {
+                        self.quoted = true;
+                        s = rest;
+                    }
+                }
+
+                if !self.quoted {
+                    return self.f.write_str(s);
+                }
+
+                if s.is_empty() {
+                    return Ok(());
+                }
+
+                if self.pending_quote {
+                    self.pending_quote = false;
+                    self.f.write_char('"')?;
+                }
+
+                if let Some(rest) = s.strip_suffix('"') {
+                    self.pending_quote = true;
+                    s = rest;
+                }
+                self.f.write_str(s)
+            }
+        }
+
+        let mut writer =
+            Writer { f, started: false, quoted: false, pending_quote: false };
+        write!(writer, "{:?}", self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unquoted() {
+        assert_eq!(debug_unquoted("").to_string(), "");
+        assert_eq!(debug_unquoted("plain").to_string(), "plain");
+        assert_eq!(debug_unquoted("a\nb\t\"c\"").to_string(), r#"a\nb\t\"c\""#,);
+        assert_eq!(
+            debug_unquoted(String::from("multi\nline\n")).to_string(),
+            r"multi\nline\n",
+        );
+        assert_eq!(debug_unquoted("\"").to_string(), r#"\""#);
+    }
+
+    #[test]
+    fn not_strings() {
+        assert_eq!(debug_unquoted(42).to_string(), "42");
+        assert_eq!(debug_unquoted('"').to_string(), r#"'"'"#);
+        assert_eq!(debug_unquoted(["a", "b"]).to_string(), r#"["a", "b"]"#);
+    }
+
+    #[test]
+    fn parts() {
+        struct Parts;
+
+        impl Debug for Parts {
+            fn fmt(&self, f: &mut Formatter) -> Result {
+                for part in ["", "\"", "a\"", "", "\"b", "\""] {
+                    f.write_str(part)?;
+                }
+                Ok(())
+            }
+        }
+
+        assert_eq!(debug_unquoted(Parts).to_string(), r#"a""b"#);
+    }
+}
@@ -0,0 +1,42 @@
+//! Internal fixed-capacity buffers.
+
+use core::str;
+
+/// String buffer for adapters that must look ahead before writing.
+///
+/// This is stored inline so that such adapters do not require allocation.
+pub(crate) struct InlineStr<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> InlineStr<N> {
+    pub(crate) const fn new() -> Self {
+        Self { bytes: [0; N], len: 0 }
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        // SAFETY: Only whole UTF-8 `char`s are written to `bytes[..len]`.
+        unsafe { str::from_utf8_unchecked(&self.bytes[..self.len]) }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Appends `c`, or returns `false` if there is not enough capacity.
+    pub(crate) fn try_push(&mut self, c: char) -> bool {
+        let end = self.len + c.len_utf8();
+        if end > N {
+            return false;
+        }
+
+        c.encode_utf8(&mut self.bytes[self.len..end]);
+        self.len = end;
+        true
+    }
+}
@@ -0,0 +1,276 @@
+use core::fmt::*;
+
+pub(crate) mod types {
+    #[allow(unused)]
+    use super::*;
+
+    /// See [`dec_bytes()`], [`dec_bytes_padded()`].
+    #[derive(Clone, Copy)]
+    pub struct DecBytes<'a, S> {
+        pub(super) bytes: &'a [u8],
+        pub(super) sep: S,
+        pub(super) padded: bool,
+    }
+
+    /// See [`c_array()`], [`rust_array()`].
+    #[derive(Clone, Copy)]
+    pub struct ByteArray<'a> {
+        pub(super) bytes: &'a [u8],
+        pub(super) per_line: usize,
+        pub(super) rust: bool,
+    }
+
+    /// See [`base32()`], [`base32_nopad()`].
+    #[derive(Clone, Copy)]
+    pub struct Base32<'a> {
+        pub(super) bytes: &'a [u8],
+        pub(super) pad: bool,
+    }
+}
+
+use types::*;
+
+/// Writes each byte in decimal with a separator between each.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::dec_bytes(&[0, 255, 16], " ");
+/// assert_eq!(value.to_string(), "0 255 16");
+/// ```
+pub fn dec_bytes<S>(bytes: &[u8], sep: S) -> DecBytes<'_, S> {
+    DecBytes { bytes, sep, padded: false }
+}
+
+/// Writes each byte as 3 zero-padded decimal digits with a separator between
+/// each.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::dec_bytes_padded(&[0, 255, 16], " ");
+/// assert_eq!(value.to_string(), "000 255 016");
+/// ```
+pub fn dec_bytes_padded<S>(bytes: &[u8], sep: S) -> DecBytes<'_, S> {
+    DecBytes { bytes, sep, padded: true }
+}
+
+/// Writes bytes as a C array initializer of hexadecimal literals.
+///
+/// Bytes are written on one line by default. The alternate flag (`{:#}`) writes
+/// `per_line` bytes on each indented line, with a trailing comma. If `per_line`
+/// is 0, all bytes are written on one indented line.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::c_array(&[0x00, 0xff, 0x10], 2);
+/// assert_eq!(value.to_string(), "{ 0x00, 0xff, 0x10 }");
+/// assert_eq!(format!("{:#}", value), "{\n    0x00, 0xff,\n    0x10,\n}");
+/// ```
+pub fn c_array(bytes: &[u8], per_line: usize) -> ByteArray<'_> {
+    ByteArray { bytes, per_line, rust: false }
+}
+
+/// Writes bytes as a Rust array expression of hexadecimal literals.
+///
+/// See [`c_array()`] for details.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::rust_array(&[0x00, 0xff, 0x10], 2);
+/// assert_eq!(value.to_string(), "[0x00, 0xff, 0x10]");
+/// assert_eq!(format!("{:#}", value), "[\n    0x00, 0xff,\n    0x10,\n]");
+/// ```
+pub fn rust_array(bytes: &[u8], per_line: usize) -> ByteArray<'_> {
+    ByteArray { bytes, per_line, rust: true }
+}
+
+/// Writes bytes in [RFC 4648](https://www.rfc-editor.org/rfc/rfc4648#section-6)
+/// base32, with `=` padding.
+///
+/// The uppercase alphabet `A`–`Z`, `2`–`7` avoids easily confused characters,
+/// which makes the output easy to read aloud or type by hand.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(fmty::base32(b"foobar").to_string(), "MZXW6YTBOI======");
+/// ```
+pub fn base32(bytes: &[u8]) -> Base32<'_> {
+    Base32 { bytes, pad: true }
+}
+
+/// Writes bytes in [RFC 4648](https://www.rfc-editor.org/rfc/rfc4648#section-6)
+/// base32, without `=` padding.
+///
+/// See [`base32()`] for details.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(fmty::base32_nopad(b"foobar").to_string(), "MZXW6YTBOI");
+/// ```
+pub fn base32_nopad(bytes: &[u8]) -> Base32<'_> {
+    Base32 { bytes, pad: false }
+}
+
+impl<S: Display> Display for DecBytes<'_, S> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        for (i, byte) in self.bytes.iter().enumerate() {
+            if i != 0 {
+                write!(f, "{}", self.sep)?;
+            }
+
+            if self.padded {
+                write!(f, "{:03}", byte)?;
+            } else {
+                write!(f, "{}", byte)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for ByteArray<'_> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let (open, close) = if self.rust { ('[', ']') } else { ('{', '}') };
+
+        f.write_char(open)?;
+
+        if self.bytes.is_empty() {
+            return f.write_char(close);
+        }
+
+        if f.alternate() {
+            let per_line =
+                if self.per_line == 0 { usize::MAX } else { self.per_line };
+
+            for (i, byte) in self.bytes.iter().enumerate() {
+                if i % per_line == 0 {
+                    f.write_str("\n    ")?;
+                } else {
+                    f.write_char(' ')?;
+                }
+                write!(f, "{:#04x},", byte)?;
+            }
+            f.write_char('\n')?;
+        } else {
+            if !self.rust {
+                f.write_char(' ')?;
+            }
+            for (i, byte) in self.bytes.iter().enumerate() {
+                if i != 0 {
+                    f.write_str(", ")?;
+                }
+                write!(f, "{:#04x}", byte)?;
+            }
+            if !self.rust {
+                f.write_char(' ')?;
+            }
+        }
+
+        f.write_char(close)
+    }
+}
+
+impl Display for Base32<'_> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        const ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+        // Number of characters needed for each chunk length.
+        const CHARS: [usize; 6] = [0, 2, 4, 5, 7, 8];
+
+        for chunk in self.bytes.chunks(5) {
+            let mut block = [0; 8];
+            block[3..3 + chunk.len()].copy_from_slice(chunk);
+
+            let bits = u64::from_be_bytes(block);
+            let len = CHARS[chunk.len()];
+
+            for i in 0..len {
+                let index = (bits >> (35 - i * 5)) & 0x1f;
+                f.write_char(ALPHABET[index as usize] as char)?;
+            }
+
+            if self.pad {
+                for _ in len..8 {
+                    f.write_char('=')?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BYTES: &[u8] = &[0, 255, 16];
+
+    #[test]
+    fn dec() {
+        assert_eq!(dec_bytes(BYTES, " ").to_string(), "0 255 16");
+        assert_eq!(dec_bytes(BYTES, ", ").to_string(), "0, 255, 16");
+        assert_eq!(dec_bytes(&[], " ").to_string(), "");
+    }
+
+    #[test]
+    fn dec_padded() {
+        assert_eq!(dec_bytes_padded(BYTES, " ").to_string(), "000 255 016");
+        assert_eq!(dec_bytes_padded(BYTES, "").to_string(), "000255016");
+        assert_eq!(dec_bytes_padded(&[], " ").to_string(), "");
+    }
+
+    #[test]
+    fn array_compact() {
+        assert_eq!(c_array(BYTES, 2).to_string(), "{ 0x00, 0xff, 0x10 }");
+        assert_eq!(rust_array(BYTES, 2).to_string(), "[0x00, 0xff, 0x10]");
+        assert_eq!(c_array(&[], 2).to_string(), "{}");
+        assert_eq!(rust_array(&[], 2).to_string(), "[]");
+    }
+
+    #[test]
+    fn array_pretty() {
+        assert_eq!(
+            format!("{:#}", c_array(BYTES, 2)),
+            "{\n    0x00, 0xff,\n    0x10,\n}",
+        );
+        assert_eq!(
+            format!("{:#}", rust_array(BYTES, 1)),
+            "[\n    0x00,\n    0xff,\n    0x10,\n]",
+        );
+        assert_eq!(
+            format!("{:#}", rust_array(BYTES, 0)),
+            "[\n    0x00, 0xff, 0x10,\n]",
+        );
+        assert_eq!(format!("{:#}", c_array(&[], 2)), "{}");
+    }
+
+    #[test]
+    fn base32_vectors() {
+        // https://www.rfc-editor.org/rfc/rfc4648#section-10
+        let cases: [(&[u8], &str); 7] = [
+            (b"", ""),
+            (b"f", "MY======"),
+            (b"fo", "MZXQ===="),
+            (b"foo", "MZXW6==="),
+            (b"foob", "MZXW6YQ="),
+            (b"fooba", "MZXW6YTB"),
+            (b"foobar", "MZXW6YTBOI======"),
+        ];
+
+        for (bytes, expected) in cases {
+            assert_eq!(base32(bytes).to_string(), expected);
+            assert_eq!(
+                base32_nopad(bytes).to_string(),
+                expected.trim_end_matches('='),
+            );
+        }
+
+        assert_eq!(base32(&[0xff; 5]).to_string(), "77777777");
+        assert_eq!(base32(&[0; 6]).to_string(), "AAAAAAAAAA======");
+    }
+}
@@ -0,0 +1,194 @@
+use core::fmt::*;
+
+use crate::truncate_chars;
+
+pub(crate) mod types {
+    #[allow(unused)]
+    use super::*;
+
+    /// See [`with_crc32()`].
+    #[derive(Clone, Copy)]
+    pub struct WithCrc32<T> {
+        pub(super) value: T,
+    }
+
+    /// See [`with_short_hash()`].
+    #[derive(Clone, Copy)]
+    pub struct WithShortHash<T> {
+        pub(super) value: T,
+        pub(super) len: usize,
+    }
+}
+
+use types::*;
+
+/// Writes a value followed by the [CRC-32](https://en.wikipedia.org/wiki/Cyclic_redundancy_check)
+/// of its output, like `hola (crc32: 6FA0F988)`.
+///
+/// The checksum is computed as the value is written and then appended, so the
+/// value is formatted only once without buffering. It uses the same
+/// polynomial as zlib, PNG, and Ethernet.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::with_crc32("123456789");
+/// assert_eq!(value.to_string(), "123456789 (crc32: CBF43926)");
+/// ```
+pub fn with_crc32<T>(value: T) -> WithCrc32<T> {
+    WithCrc32 { value }
+}
+
+/// Writes a value preceded by the first `len` hexadecimal digits of the
+/// 64-bit [FNV-1a](https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function)
+/// hash of its output, like `[4029fb] hola`.
+///
+/// At most 16 digits are written. The hash is stable across platforms and
+/// releases, but it is not cryptographic and must not be used to detect
+/// tampering.
+///
+/// Because the hash must be known before writing, the value is formatted
+/// twice.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::with_short_hash("hola", 6);
+/// assert_eq!(value.to_string(), "[4029fb] hola");
+/// ```
+pub fn with_short_hash<T>(value: T, len: usize) -> WithShortHash<T> {
+    WithShortHash { value, len }
+}
+
+/// Lookup table for the reversed polynomial `0xEDB88320`.
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+
+        while bit < 8 {
+            crc = if crc & 1 == 0 { crc >> 1 } else { (crc >> 1) ^ 0xEDB88320 };
+            bit += 1;
+        }
+
+        table[i] = crc;
+        i += 1;
+    }
+
+    table
+};
+
+impl<T: Display> Display for WithCrc32<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        struct Writer<'a, 'b> {
+            f: &'a mut Formatter<'b>,
+            crc: u32,
+        }
+
+        impl Write for Writer<'_, '_> {
+            fn write_str(&mut self, s: &str) -> Result {
+                for &byte in s.as_bytes() {
+                    let i = (self.crc as u8 ^ byte) as usize;
+                    self.crc = (self.crc >> 8) ^ CRC32_TABLE[i];
+                }
+                self.f.write_str(s)
+            }
+        }
+
+        let mut writer = Writer { f, crc: !0 };
+        write!(writer, "{}", self.value)?;
+
+        let crc = !writer.crc;
+        write!(f, " (crc32: {:08X})", crc)
+    }
+}
+
+impl<T: Display> Display for WithShortHash<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        struct Hasher {
+            hash: u64,
+        }
+
+        impl Write for Hasher {
+            fn write_str(&mut self, s: &str) -> Result {
+                for &byte in s.as_bytes() {
+                    self.hash ^= byte as u64;
+                    self.hash = self.hash.wrapping_mul(0x100000001b3);
+                }
+                Ok(())
+            }
+        }
+
+        let mut hasher = Hasher { hash: 0xcbf29ce484222325 };
+        write!(hasher, "{}", self.value)?;
+
+        write!(
+            f,
+            "[{}] {}",
+            truncate_chars(format_args!("{:016x}", hasher.hash), self.len),
+            self.value,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crc32() {
+        let cases = [
+            ("", "00000000"),
+            ("a", "E8B7BE43"),
+            ("123456789", "CBF43926"),
+            ("The quick brown fox jumps over the lazy dog", "414FA339"),
+        ];
+
+        for (value, crc) in cases {
+            let expected = format!("{value} (crc32: {crc})");
+            assert_eq!(with_crc32(value).to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn crc32_parts() {
+        let value = format_args!("{}{}{}", "1234", 5, "6789");
+        assert_eq!(
+            with_crc32(value).to_string(),
+            "123456789 (crc32: CBF43926)"
+        );
+    }
+
+    #[test]
+    fn short_hash() {
+        let cases = [
+            ("", "cbf29ce484222325"),
+            ("a", "af63dc4c8601ec8c"),
+            ("foobar", "85944171f73967e8"),
+            ("123456789", "06d5573923c6cdfc"),
+        ];
+
+        for (value, hash) in cases {
+            assert_eq!(
+                with_short_hash(value, 16).to_string(),
+                format!("[{hash}] {value}"),
+            );
+            assert_eq!(
+                with_short_hash(value, 6).to_string(),
+                format!("[{}] {value}", &hash[..6]),
+            );
+        }
+
+        assert_eq!(with_short_hash("a", 0).to_string(), "[] a");
+        assert_eq!(
+            with_short_hash("a", 99).to_string(),
+            "[af63dc4c8601ec8c] a"
+        );
+
+        let value = crate::concat_tuple(("foo", 'b', "ar"));
+        assert_eq!(with_short_hash(value, 4).to_string(), "[8594] foobar");
+    }
+}
@@ -0,0 +1,99 @@
+use core::{cmp::Ordering, fmt::*};
+
+pub(crate) mod types {
+    #[allow(unused)]
+    use super::*;
+
+    /// See [`ordering()`], [`ordering_words()`], [`cmp_symbol()`].
+    #[derive(Clone, Copy)]
+    pub struct OrderingFmt {
+        pub(super) ord: Ordering,
+        pub(super) words: bool,
+    }
+}
+
+use types::*;
+
+/// Writes an [`Ordering`] as `<`, `=`, or `>`.
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(fmty::ordering(Ordering::Less).to_string(), "<");
+/// ```
+pub fn ordering(ord: Ordering) -> OrderingFmt {
+    OrderingFmt { ord, words: false }
+}
+
+/// Writes an [`Ordering`] as `less than`, `equal to`, or `greater than`.
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+///
+/// let value = fmty::ordering_words(Ordering::Greater);
+/// assert_eq!(format!("3 is {} 2", value), "3 is greater than 2");
+/// ```
+pub fn ordering_words(ord: Ordering) -> OrderingFmt {
+    OrderingFmt { ord, words: true }
+}
+
+/// Compares two values and writes the result as `<`, `=`, or `>`.
+///
+/// This is equivalent to <code>[ordering]\(a.[cmp](Ord::cmp)(&b)\)</code>.
+///
+/// # Examples
+///
+/// ```
+/// let (a, b) = (1, 2);
+/// let value = fmty::cmp_symbol(a, b);
+/// assert_eq!(format!("{} {} {}", a, value, b), "1 < 2");
+/// ```
+pub fn cmp_symbol<T: Ord>(a: T, b: T) -> OrderingFmt {
+    ordering(a.cmp(&b))
+}
+
+impl Display for OrderingFmt {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        f.write_str(match (self.ord, self.words) {
+            (Ordering::Less, false) => "<",
+            (Ordering::Equal, false) => "=",
+            (Ordering::Greater, false) => ">",
+            (Ordering::Less, true) => "less than",
+            (Ordering::Equal, true) => "equal to",
+            (Ordering::Greater, true) => "greater than",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn symbols() {
+        assert_eq!(ordering(Ordering::Less).to_string(), "<");
+        assert_eq!(ordering(Ordering::Equal).to_string(), "=");
+        assert_eq!(ordering(Ordering::Greater).to_string(), ">");
+    }
+
+    #[test]
+    fn words() {
+        assert_eq!(ordering_words(Ordering::Less).to_string(), "less than");
+        assert_eq!(ordering_words(Ordering::Equal).to_string(), "equal to");
+        assert_eq!(
+            ordering_words(Ordering::Greater).to_string(),
+            "greater than",
+        );
+    }
+
+    #[test]
+    fn compare() {
+        assert_eq!(cmp_symbol(1, 2).to_string(), "<");
+        assert_eq!(cmp_symbol("b", "b").to_string(), "=");
+        assert_eq!(cmp_symbol(&[1, 2][..], &[1][..]).to_string(), ">");
+    }
+}
@@ -1,4 +1,4 @@
-use crate::types::NoOp;
+use crate::types::{ConcatTuple, NoOp};
 use core::fmt::{self, Debug, Display, Formatter};
 
 pub(crate) mod types {
@@ -8,7 +8,8 @@ pub(crate) mod types {
     /// See [`cond()`], [`cond_option()`].
     pub type Cond<T> = CondOr<T, NoOp>;
 
-    /// See [`cond_or()`], [`cond_option_or()`], [`cond_result()`].
+    /// See [`cond_or()`], [`cond_option_or()`], [`cond_result()`],
+    /// [`transform_if()`], [`transform_if_some()`].
     #[derive(Clone, Copy)]
     pub struct CondOr<T, U = T> {
         pub(super) value: Result<T, U>,
@@ -19,6 +20,32 @@ pub(crate) mod types {
     pub struct CondWith<F> {
         pub(super) make_value: F,
     }
+
+    /// See [`opt_prefix()`].
+    pub type OptPrefix<P, T> = Cond<ConcatTuple<(P, T)>>;
+
+    /// See [`opt_wrap()`].
+    pub type OptWrap<P, T, S> = Cond<ConcatTuple<(P, T, S)>>;
+
+    /// See [`option_display()`].
+    #[derive(Clone, Copy)]
+    pub struct OptionDisplay<T> {
+        pub(super) option: Option<T>,
+    }
+
+    /// See [`result_display()`].
+    #[derive(Clone, Copy)]
+    pub struct ResultDisplay<T, E> {
+        pub(super) result: Result<T, E>,
+    }
+
+    /// See [`catch_fmt()`].
+    #[cfg(feature = "std")]
+    #[derive(Clone, Copy)]
+    pub struct CatchFmt<T, U> {
+        pub(super) value: T,
+        pub(super) fallback: U,
+    }
 }
 
 use types::*;
@@ -122,6 +149,163 @@ where
     CondWith { make_value: f }
 }
 
+/// Conditionally applies a transform to a value, or writes the value unchanged
+/// if `false`.
+///
+/// Both outcomes have the same type, so this can be used to conditionally apply
+/// another adapter.
+///
+/// # Examples
+///
+/// ```
+/// let shout = true;
+/// let value = fmty::transform_if(shout, "hola", fmty::to_ascii_uppercase);
+/// assert_eq!(value.to_string(), "HOLA");
+///
+/// let value = fmty::transform_if(!shout, "hola", fmty::to_ascii_uppercase);
+/// assert_eq!(value.to_string(), "hola");
+/// ```
+pub fn transform_if<T, R, F>(cond: bool, value: T, f: F) -> CondOr<R, T>
+where
+    F: FnOnce(T) -> R,
+{
+    cond_result(if cond { Ok(f(value)) } else { Err(value) })
+}
+
+/// Applies a transform to a value with the contents of an [`Option`], or writes
+/// the value unchanged if [`None`].
+///
+/// # Examples
+///
+/// ```
+/// let max_len = Some(2);
+/// let value = fmty::transform_if_some(max_len, "hola", fmty::truncate_chars);
+/// assert_eq!(value.to_string(), "ho");
+///
+/// let value = fmty::transform_if_some(None, "hola", fmty::truncate_chars);
+/// assert_eq!(value.to_string(), "hola");
+/// ```
+pub fn transform_if_some<T, U, R, F>(
+    option: Option<U>,
+    value: T,
+    f: F,
+) -> CondOr<R, T>
+where
+    F: FnOnce(T, U) -> R,
+{
+    cond_result(match option {
+        Some(arg) => Ok(f(value, arg)),
+        None => Err(value),
+    })
+}
+
+/// Writes `prefix` followed by the [`Some`] value, or nothing if [`None`].
+///
+/// This is like [`opt_wrap()`] without a suffix.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::opt_prefix("v", Some(2));
+/// assert_eq!(value.to_string(), "v2");
+///
+/// let value = fmty::opt_prefix("v", None::<i32>);
+/// assert_eq!(value.to_string(), "");
+/// ```
+pub fn opt_prefix<P, T>(prefix: P, option: Option<T>) -> OptPrefix<P, T> {
+    cond_option(option.map(|value| crate::concat_tuple((prefix, value))))
+}
+
+/// Writes the [`Some`] value between `prefix` and `suffix`, or nothing if
+/// [`None`].
+///
+/// This is useful for optional parts of a sentence, where the surrounding
+/// punctuation should only appear with the value.
+///
+/// # Examples
+///
+/// ```
+/// let name = |middle: Option<&str>| {
+///     format!("Jane{} Doe", fmty::opt_wrap(" (", middle, ")"))
+/// };
+///
+/// assert_eq!(name(Some("Q")), "Jane (Q) Doe");
+/// assert_eq!(name(None), "Jane Doe");
+/// ```
+pub fn opt_wrap<P, T, S>(
+    prefix: P,
+    option: Option<T>,
+    suffix: S,
+) -> OptWrap<P, T, S> {
+    cond_option(
+        option.map(|value| crate::concat_tuple((prefix, value, suffix))),
+    )
+}
+
+/// Writes an [`Option`] like its [`Debug`] output, but with the [`Display`]
+/// output of the inner value.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(fmty::option_display(Some("hola")).to_string(), "Some(hola)");
+/// assert_eq!(fmty::option_display(None::<&str>).to_string(), "None");
+/// ```
+pub fn option_display<T>(option: Option<T>) -> OptionDisplay<T> {
+    OptionDisplay { option }
+}
+
+/// Writes a [`Result`] like its [`Debug`] output, but with the [`Display`]
+/// output of the inner value.
+///
+/// # Examples
+///
+/// ```
+/// let ok = fmty::result_display::<_, &str>(Ok("hola"));
+/// assert_eq!(ok.to_string(), "Ok(hola)");
+///
+/// let err = fmty::result_display::<&str, _>(Err("mundo"));
+/// assert_eq!(err.to_string(), "Err(mundo)");
+/// ```
+pub fn result_display<T, E>(result: Result<T, E>) -> ResultDisplay<T, E> {
+    ResultDisplay { result }
+}
+
+/// Writes a value, or its fallback if formatting the value panics.
+///
+/// The value is first written to a [`String`] within
+/// [`catch_unwind()`](std::panic::catch_unwind), so that partial output from a
+/// panicking value is not written. This requires the value to be
+/// [`RefUnwindSafe`](std::panic::RefUnwindSafe).
+///
+/// The panic is still reported by the panic hook, and panics are not caught if
+/// the program aborts on panic.
+///
+/// This requires the `std` feature.
+///
+/// # Examples
+///
+/// ```
+/// struct Broken;
+///
+/// impl std::fmt::Display for Broken {
+///     fn fmt(&self, _: &mut std::fmt::Formatter) -> std::fmt::Result {
+///         panic!("broken");
+///     }
+/// }
+///
+/// # std::panic::set_hook(Box::new(|_| {}));
+/// assert_eq!(fmty::catch_fmt("hola", "?").to_string(), "hola");
+/// assert_eq!(fmty::catch_fmt(Broken, "?").to_string(), "?");
+/// ```
+#[cfg(feature = "std")]
+pub fn catch_fmt<T, U>(value: T, fallback: U) -> CatchFmt<T, U>
+where
+    T: std::panic::RefUnwindSafe,
+{
+    CatchFmt { value, fallback }
+}
+
 impl<T: Debug, U: Debug> Debug for CondOr<T, U> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match &self.value {
@@ -191,3 +375,67 @@ where
         Ok(())
     }
 }
+
+impl<T: Display> Display for OptionDisplay<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match &self.option {
+            Some(value) => write!(f, "Some({})", value),
+            None => f.write_str("None"),
+        }
+    }
+}
+
+impl<T: Display, E: Display> Display for ResultDisplay<T, E> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match &self.result {
+            Ok(value) => write!(f, "Ok({})", value),
+            Err(value) => write!(f, "Err({})", value),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, U> Display for CatchFmt<T, U>
+where
+    T: Display + std::panic::RefUnwindSafe,
+    U: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        use std::{fmt::Write, panic};
+
+        let value = &self.value;
+        let result = panic::catch_unwind(|| {
+            let mut buf = String::new();
+            write!(buf, "{}", value).map(|_| buf)
+        });
+
+        match result {
+            Ok(buf) => f.write_str(&buf?),
+            Err(_) => write!(f, "{}", self.fallback),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    struct Panics;
+
+    impl Display for Panics {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            f.write_str("partial")?;
+            panic!("cannot format");
+        }
+    }
+
+    #[test]
+    fn catch() {
+        assert_eq!(catch_fmt(42, "?").to_string(), "42");
+        assert_eq!(catch_fmt(Panics, "<error>").to_string(), "<error>");
+        assert_eq!(
+            format_args!("[{}]", catch_fmt(Panics, "?")).to_string(),
+            "[?]",
+        );
+    }
+}
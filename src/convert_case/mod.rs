@@ -17,6 +17,38 @@ pub(crate) mod types {
     pub struct ToAsciiLowercase<T> {
         pub(super) value: T,
     }
+
+    /// See [`toggle_case()`].
+    #[derive(Clone, Copy)]
+    pub struct ToggleCase<T> {
+        pub(super) value: T,
+    }
+
+    /// See [`sentence()`].
+    #[derive(Clone, Copy)]
+    pub struct Sentence<T> {
+        pub(super) value: T,
+    }
+
+    /// See [`to_uppercase_locale()`].
+    #[derive(Clone, Copy)]
+    pub struct ToUppercaseLocale<T> {
+        pub(super) value: T,
+        pub(super) locale: Locale,
+    }
+
+    /// Language whose case rules are used.
+    ///
+    /// See [`to_uppercase_locale()`](crate::to_uppercase_locale()).
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum Locale {
+        /// Locale-independent rules of [`char::to_uppercase()`].
+        Default,
+
+        /// Turkish and Azerbaijani rules, where `i` and `ı` are separate
+        /// letters whose uppercase forms are `İ` and `I`.
+        Turkish,
+    }
 }
 
 use types::*;
@@ -51,6 +83,67 @@ pub fn to_ascii_lowercase<T>(value: T) -> ToAsciiLowercase<T> {
     ToAsciiLowercase { value }
 }
 
+/// Swaps the case of each letter, leaving other characters unchanged.
+///
+/// Letters are converted with [`char::to_uppercase()`] and
+/// [`char::to_lowercase()`], so some letters become multiple characters and
+/// the result may not toggle back to the original. For ASCII, toggling twice
+/// always produces the original.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::toggle_case("Hola, Mundo!");
+/// assert_eq!(value.to_string(), "hOLA, mUNDO!");
+/// ```
+pub fn toggle_case<T>(value: T) -> ToggleCase<T> {
+    ToggleCase { value }
+}
+
+/// Writes a value as a sentence by capitalizing its first [`char`] and ending
+/// it with `.` if it does not already end with `.`, `!`, or `?`.
+///
+/// The first [`char`] is converted with [`char::to_uppercase()`]. Only the last
+/// [`char`] is checked for punctuation, so trailing whitespace is followed by
+/// `.`. Empty values write nothing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(fmty::sentence("hola mundo").to_string(), "Hola mundo.");
+/// assert_eq!(fmty::sentence("really?").to_string(), "Really?");
+/// ```
+pub fn sentence<T>(value: T) -> Sentence<T> {
+    Sentence { value }
+}
+
+/// Converts to uppercase using the rules of `locale`.
+///
+/// With [`Locale::Default`], each [`char`] is converted with
+/// [`char::to_uppercase()`], which matches
+/// [`str::to_uppercase()`](https://doc.rust-lang.org/std/primitive.str.html#method.to_uppercase).
+/// With [`Locale::Turkish`], dotted `i` becomes `İ` rather than `I`. Dotless
+/// `ı` becomes `I` in either locale. No other locale-specific rules are
+/// applied.
+///
+/// # Examples
+///
+/// ```
+/// use fmty::types::Locale;
+///
+/// let value = fmty::to_uppercase_locale("istanbul", Locale::Default);
+/// assert_eq!(value.to_string(), "ISTANBUL");
+///
+/// let value = fmty::to_uppercase_locale("istanbul", Locale::Turkish);
+/// assert_eq!(value.to_string(), "İSTANBUL");
+/// ```
+pub fn to_uppercase_locale<T>(
+    value: T,
+    locale: Locale,
+) -> ToUppercaseLocale<T> {
+    ToUppercaseLocale { value, locale }
+}
+
 /// Single writer for ASCII to reduce code generation.
 struct AsciiWriter<'a, 'b> {
     f: &'b mut Formatter<'a>,
@@ -97,3 +190,107 @@ impl<T: Display> Display for ToAsciiUppercase<T> {
         write!(AsciiWriter { f, uppercase: true }, "{}", self.value)
     }
 }
+
+/// Writer for [`ToggleCase`], shared by its `Debug` and `Display` impls.
+struct ToggleCaseWriter<'a, 'b> {
+    f: &'b mut Formatter<'a>,
+}
+
+impl Write for ToggleCaseWriter<'_, '_> {
+    fn write_str(&mut self, s: &str) -> Result {
+        for c in s.chars() {
+            self.write_char(c)?;
+        }
+        Ok(())
+    }
+
+    fn write_char(&mut self, c: char) -> Result {
+        if c.is_lowercase() {
+            c.to_uppercase().try_for_each(|c| self.f.write_char(c))
+        } else if c.is_uppercase() {
+            c.to_lowercase().try_for_each(|c| self.f.write_char(c))
+        } else {
+            self.f.write_char(c)
+        }
+    }
+}
+
+impl<T: Debug> Debug for ToggleCase<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(ToggleCaseWriter { f }, "{:?}", self.value)
+    }
+}
+
+impl<T: Display> Display for ToggleCase<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(ToggleCaseWriter { f }, "{}", self.value)
+    }
+}
+
+impl<T: Display> Display for Sentence<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        struct Writer<'a, 'b> {
+            f: &'a mut Formatter<'b>,
+            last: Option<char>,
+        }
+
+        impl Write for Writer<'_, '_> {
+            fn write_str(&mut self, mut s: &str) -> Result {
+                let last = match s.chars().next_back() {
+                    Some(c) => c,
+                    None => return Ok(()),
+                };
+
+                if self.last.is_none() {
+                    let mut chars = s.chars();
+                    if let Some(first) = chars.next() {
+                        first
+                            .to_uppercase()
+                            .try_for_each(|c| self.f.write_char(c))?;
+                    }
+                    s = chars.as_str();
+                }
+
+                self.last = Some(last);
+                self.f.write_str(s)
+            }
+        }
+
+        let mut writer = Writer { f, last: None };
+        write!(writer, "{}", self.value)?;
+
+        match writer.last {
+            None | Some('.' | '!' | '?') => Ok(()),
+            Some(_) => f.write_char('.'),
+        }
+    }
+}
+
+impl<T: Display> Display for ToUppercaseLocale<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        struct Writer<'a, 'b> {
+            f: &'a mut Formatter<'b>,
+            locale: Locale,
+        }
+
+        impl Write for Writer<'_, '_> {
+            fn write_str(&mut self, s: &str) -> Result {
+                for c in s.chars() {
+                    self.write_char(c)?;
+                }
+                Ok(())
+            }
+
+            fn write_char(&mut self, c: char) -> Result {
+                match (self.locale, c) {
+                    (Locale::Turkish, 'i') => self.f.write_char('İ'),
+                    _ => {
+                        c.to_uppercase().try_for_each(|c| self.f.write_char(c))
+                    }
+                }
+            }
+        }
+
+        write!(Writer { f, locale: self.locale }, "{}", self.value)
+    }
+}
@@ -43,3 +43,104 @@ mod to_ascii_lowercase {
         assert_eq!(expected, result);
     }
 }
+
+mod toggle_case {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(toggle_case("").to_string(), "");
+        assert_eq!(toggle_case("aBc 123 _-!").to_string(), "AbC 123 _-!");
+        assert_eq!(toggle_case("Ñandú ÉTÉ").to_string(), "ñANDÚ été");
+        assert_eq!(toggle_case("ß").to_string(), "SS");
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", toggle_case("")), "\"\"");
+        assert_eq!(format!("{:?}", toggle_case("Ab\tc")), "\"aB\\TC\"");
+    }
+
+    #[proptest]
+    fn ascii_twice(#[strategy("[ -~]*")] s: String) {
+        assert_eq!(toggle_case(toggle_case(&s)).to_string(), s);
+    }
+}
+
+mod sentence {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let cases = [
+            ("", ""),
+            ("a", "A."),
+            ("hello world", "Hello world."),
+            ("Already done.", "Already done."),
+            ("what?", "What?"),
+            ("wow!", "Wow!"),
+            ("42 apples", "42 apples."),
+            ("ßig", "SSig."),
+            ("trailing ", "Trailing ."),
+        ];
+
+        for (value, expected) in cases {
+            assert_eq!(sentence(value).to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn parts() {
+        let value = crate::concat_tuple(("", "é", 't', "é", ""));
+        assert_eq!(sentence(value).to_string(), "Été.");
+
+        let value = crate::concat_tuple(("is it", '?', ""));
+        assert_eq!(sentence(value).to_string(), "Is it?");
+    }
+}
+
+mod to_uppercase_locale {
+    use super::*;
+
+    #[proptest]
+    fn default(s: String) {
+        let expected = s.to_uppercase();
+        let result = to_uppercase_locale(s, Locale::Default).to_string();
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn turkish() {
+        let cases = [
+            ("", "", ""),
+            ("i", "I", "İ"),
+            ("ı", "I", "I"),
+            ("I", "I", "I"),
+            ("İ", "İ", "İ"),
+            ("diyarbakır", "DIYARBAKIR", "DİYARBAKIR"),
+            ("ßi", "SSI", "SSİ"),
+        ];
+
+        for (value, default, turkish) in cases {
+            assert_eq!(
+                to_uppercase_locale(value, Locale::Default).to_string(),
+                default,
+            );
+            assert_eq!(
+                to_uppercase_locale(value, Locale::Turkish).to_string(),
+                turkish,
+            );
+        }
+    }
+
+    #[test]
+    fn parts() {
+        let value = crate::concat_tuple(("ı", 'i', "", "ğ"));
+
+        assert_eq!(
+            to_uppercase_locale(value, Locale::Turkish).to_string(),
+            "IİĞ"
+        );
+    }
+}
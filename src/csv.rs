@@ -0,0 +1,294 @@
+use core::fmt::*;
+
+pub(crate) mod types {
+    #[allow(unused)]
+    use super::*;
+
+    /// See [`csv_field()`].
+    #[derive(Clone, Copy)]
+    pub struct CsvField<T> {
+        pub(super) value: T,
+    }
+
+    /// See [`csv_row()`], [`csv_row_crlf()`].
+    #[derive(Clone, Copy)]
+    pub struct CsvRow<I> {
+        pub(super) iter: I,
+        pub(super) crlf: bool,
+    }
+
+    /// See [`tsv_field()`].
+    #[derive(Clone, Copy)]
+    pub struct TsvField<T> {
+        pub(super) value: T,
+    }
+
+    /// See [`tsv_record()`].
+    #[derive(Clone, Copy)]
+    pub struct TsvRecord<I> {
+        pub(super) iter: I,
+    }
+}
+
+use types::*;
+
+/// Escapes a value as a CSV field per [RFC 4180](https://www.rfc-editor.org/rfc/rfc4180).
+///
+/// If the value contains `,`, `"`, `\r`, or `\n`, it is placed between `"`
+/// and each `"` is doubled. Otherwise, it is written unchanged.
+///
+/// Because quoting depends on the entire value, the value is formatted twice:
+/// once to check for special characters and once to write it.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(fmty::csv_field("hola").to_string(), "hola");
+/// assert_eq!(fmty::csv_field("a,b").to_string(), "\"a,b\"");
+/// assert_eq!(fmty::csv_field("\"hi\"").to_string(), "\"\"\"hi\"\"\"");
+/// ```
+pub fn csv_field<T>(value: T) -> CsvField<T> {
+    CsvField { value }
+}
+
+/// Writes [`Iterator`] items as a CSV record per [RFC 4180](https://www.rfc-editor.org/rfc/rfc4180).
+///
+/// Each item is escaped with [`csv_field()`] and items are separated by `,`.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::csv_row(["name", "Doe, Jane"]);
+/// assert_eq!(value.to_string(), "name,\"Doe, Jane\"");
+/// ```
+pub fn csv_row<I>(iter: I) -> CsvRow<I::IntoIter>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+{
+    CsvRow { iter: iter.into_iter(), crlf: false }
+}
+
+/// Writes [`Iterator`] items as a CSV record followed by `\r\n`.
+///
+/// This is like [`csv_row()`], but writes a complete CSV line.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::csv_row_crlf(["name", "Doe, Jane"]);
+/// assert_eq!(value.to_string(), "name,\"Doe, Jane\"\r\n");
+/// ```
+pub fn csv_row_crlf<I>(iter: I) -> CsvRow<I::IntoIter>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+{
+    CsvRow { iter: iter.into_iter(), crlf: true }
+}
+
+/// Escapes a value as a TSV field.
+///
+/// TSV has no quoting, so `\t`, `\n`, `\r`, and `\` are written as the escape
+/// sequences `\t`, `\n`, `\r`, and `\\`. This is the convention of
+/// PostgreSQL's text format, and keeps each record on one line.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(fmty::tsv_field("hola").to_string(), "hola");
+/// assert_eq!(fmty::tsv_field("a\tb\n").to_string(), r"a\tb\n");
+/// ```
+pub fn tsv_field<T>(value: T) -> TsvField<T> {
+    TsvField { value }
+}
+
+/// Writes [`Iterator`] items as a TSV record.
+///
+/// Each item is escaped with [`tsv_field()`] and items are separated by `\t`.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::tsv_record(["name", "Doe\tJane"]);
+/// assert_eq!(value.to_string(), "name\tDoe\\tJane");
+/// ```
+pub fn tsv_record<I>(iter: I) -> TsvRecord<I::IntoIter>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+{
+    TsvRecord { iter: iter.into_iter() }
+}
+
+impl<T: Display> Display for CsvField<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        /// Checks whether the field must be quoted.
+        struct Probe {
+            quote: bool,
+        }
+
+        impl Write for Probe {
+            fn write_str(&mut self, s: &str) -> Result {
+                self.quote |= s.contains(&[',', '"', '\r', '\n'][..]);
+                Ok(())
+            }
+        }
+
+        /// Doubles each `"`.
+        struct Writer<'a, 'b> {
+            f: &'a mut Formatter<'b>,
+        }
+
+        impl Write for Writer<'_, '_> {
+            fn write_str(&mut self, s: &str) -> Result {
+                for (i, part) in s.split('"').enumerate() {
+                    if i != 0 {
+                        self.f.write_str("\"\"")?;
+                    }
+                    self.f.write_str(part)?;
+                }
+                Ok(())
+            }
+        }
+
+        let mut probe = Probe { quote: false };
+        write!(probe, "{}", self.value)?;
+
+        if probe.quote {
+            f.write_char('"')?;
+            write!(Writer { f }, "{}", self.value)?;
+            f.write_char('"')
+        } else {
+            write!(f, "{}", self.value)
+        }
+    }
+}
+
+impl<I> Display for CsvRow<I>
+where
+    I: Iterator + Clone,
+    I::Item: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        for (i, item) in self.iter.clone().enumerate() {
+            if i != 0 {
+                f.write_char(',')?;
+            }
+            write!(f, "{}", csv_field(item))?;
+        }
+
+        if self.crlf {
+            f.write_str("\r\n")?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Display> Display for TsvField<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        struct Writer<'a, 'b> {
+            f: &'a mut Formatter<'b>,
+        }
+
+        impl Write for Writer<'_, '_> {
+            fn write_str(&mut self, s: &str) -> Result {
+                let mut rest = s;
+
+                while let Some(i) = rest.find(&['\t', '\n', '\r', '\\'][..]) {
+                    self.f.write_str(&rest[..i])?;
+                    self.f.write_str(match rest.as_bytes()[i] {
+                        b'\t' => r"\t",
+                        b'\n' => r"\n",
+                        b'\r' => r"\r",
+                        _ => r"\\",
+                    })?;
+                    rest = &rest[i + 1..];
+                }
+
+                self.f.write_str(rest)
+            }
+        }
+
+        write!(Writer { f }, "{}", self.value)
+    }
+}
+
+impl<I> Display for TsvRecord<I>
+where
+    I: Iterator + Clone,
+    I::Item: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        for (i, item) in self.iter.clone().enumerate() {
+            if i != 0 {
+                f.write_char('\t')?;
+            }
+            write!(f, "{}", tsv_field(item))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn field() {
+        let cases = [
+            ("", ""),
+            ("plain", "plain"),
+            ("a,b", "\"a,b\""),
+            ("say \"hi\"", "\"say \"\"hi\"\"\""),
+            ("line\nbreak", "\"line\nbreak\""),
+            ("carriage\rreturn", "\"carriage\rreturn\""),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(csv_field(input).to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn field_parts() {
+        assert_eq!(
+            csv_field(format_args!("a{}b", "\"")).to_string(),
+            "\"a\"\"b\"",
+        );
+    }
+
+    #[test]
+    fn row() {
+        let fields = ["1", "a,b", "\"q\"", "x\ny"];
+        let expected = "1,\"a,b\",\"\"\"q\"\"\",\"x\ny\"";
+
+        assert_eq!(csv_row(fields).to_string(), expected);
+        assert_eq!(csv_row_crlf(fields).to_string(), format!("{expected}\r\n"));
+        assert_eq!(csv_row_crlf([""; 0]).to_string(), "\r\n");
+    }
+
+    #[test]
+    fn tsv() {
+        let cases = [
+            ("", ""),
+            ("plain, text", "plain, text"),
+            ("a\tb", r"a\tb"),
+            ("line\nbreak\r\n", r"line\nbreak\r\n"),
+            (r"C:\dir", r"C:\\dir"),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(tsv_field(input).to_string(), expected);
+        }
+
+        assert_eq!(tsv_field(format_args!("a{}b", '\t')).to_string(), r"a\tb",);
+    }
+
+    #[test]
+    fn tsv_records() {
+        let fields = ["1", "a\tb", "x\ny", ""];
+        assert_eq!(tsv_record(fields).to_string(), "1\ta\\tb\tx\\ny\t");
+        assert_eq!(tsv_record([""; 0]).to_string(), "");
+    }
+}
@@ -0,0 +1,190 @@
+use core::{fmt::*, time::Duration};
+
+pub(crate) mod types {
+    #[allow(unused)]
+    use super::*;
+
+    /// See [`time_ago()`].
+    #[derive(Clone, Copy)]
+    pub struct TimeAgo {
+        pub(super) secs: u64,
+    }
+
+    /// See [`iso8601_duration()`], [`iso8601_duration_exact()`].
+    #[derive(Clone, Copy)]
+    pub struct Iso8601Duration {
+        pub(super) secs: u64,
+        pub(super) nanos: u32,
+    }
+}
+
+use types::*;
+
+const MINUTE: u64 = 60;
+const HOUR: u64 = 60 * MINUTE;
+const DAY: u64 = 24 * HOUR;
+
+/// Writes elapsed seconds as relative time in the coarsest whole unit.
+///
+/// Units are seconds, minutes, hours, and days. Partial units are rounded
+/// down, and `0` is written as `just now`.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(fmty::time_ago(0).to_string(), "just now");
+/// assert_eq!(fmty::time_ago(300).to_string(), "5 minutes ago");
+/// assert_eq!(fmty::time_ago(3600).to_string(), "1 hour ago");
+/// ```
+pub fn time_ago(secs: u64) -> TimeAgo {
+    TimeAgo { secs }
+}
+
+/// Writes seconds as an [ISO 8601 duration](https://en.wikipedia.org/wiki/ISO_8601#Durations)
+/// in hours, minutes, and seconds.
+///
+/// Components that are zero are omitted, except for `PT0S`. Hours are not
+/// carried over into days, since days may not always be 24 hours long.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(fmty::iso8601_duration(3723).to_string(), "PT1H2M3S");
+/// assert_eq!(fmty::iso8601_duration(3600).to_string(), "PT1H");
+/// assert_eq!(fmty::iso8601_duration(0).to_string(), "PT0S");
+/// ```
+pub fn iso8601_duration(secs: u64) -> Iso8601Duration {
+    Iso8601Duration { secs, nanos: 0 }
+}
+
+/// Writes a [`Duration`] as an [ISO 8601 duration](https://en.wikipedia.org/wiki/ISO_8601#Durations),
+/// including fractional seconds.
+///
+/// Fractional seconds are written without trailing zeros. See
+/// [`iso8601_duration()`] for details.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// let value = fmty::iso8601_duration_exact(Duration::from_millis(61_500));
+/// assert_eq!(value.to_string(), "PT1M1.5S");
+/// ```
+pub fn iso8601_duration_exact(duration: Duration) -> Iso8601Duration {
+    Iso8601Duration { secs: duration.as_secs(), nanos: duration.subsec_nanos() }
+}
+
+impl Display for TimeAgo {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let secs = self.secs;
+
+        let (n, unit) = if secs == 0 {
+            return f.write_str("just now");
+        } else if secs < MINUTE {
+            (secs, "second")
+        } else if secs < HOUR {
+            (secs / MINUTE, "minute")
+        } else if secs < DAY {
+            (secs / HOUR, "hour")
+        } else {
+            (secs / DAY, "day")
+        };
+
+        let s = if n == 1 { "" } else { "s" };
+        write!(f, "{} {}{} ago", n, unit, s)
+    }
+}
+
+impl Display for Iso8601Duration {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let hours = self.secs / HOUR;
+        let minutes = self.secs % HOUR / MINUTE;
+        let secs = self.secs % MINUTE;
+
+        f.write_str("PT")?;
+
+        if hours != 0 {
+            write!(f, "{}H", hours)?;
+        }
+        if minutes != 0 {
+            write!(f, "{}M", minutes)?;
+        }
+
+        if self.nanos != 0 {
+            let mut nanos = self.nanos;
+            let mut digits = 9;
+            while nanos % 10 == 0 {
+                nanos /= 10;
+                digits -= 1;
+            }
+            write!(f, "{}.{:0digits$}S", secs, nanos, digits = digits)
+        } else if secs != 0 || self.secs == 0 {
+            write!(f, "{}S", secs)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn thresholds() {
+        let cases = [
+            (0, "just now"),
+            (1, "1 second ago"),
+            (59, "59 seconds ago"),
+            (60, "1 minute ago"),
+            (119, "1 minute ago"),
+            (120, "2 minutes ago"),
+            (HOUR - 1, "59 minutes ago"),
+            (HOUR, "1 hour ago"),
+            (2 * HOUR + 59, "2 hours ago"),
+            (DAY - 1, "23 hours ago"),
+            (DAY, "1 day ago"),
+            (3 * DAY, "3 days ago"),
+            (u64::MAX, "213503982334601 days ago"),
+        ];
+
+        for (secs, expected) in cases {
+            assert_eq!(time_ago(secs).to_string(), expected, "for {secs}s");
+        }
+    }
+
+    #[test]
+    fn iso8601() {
+        let cases = [
+            (0, "PT0S"),
+            (1, "PT1S"),
+            (60, "PT1M"),
+            (61, "PT1M1S"),
+            (HOUR, "PT1H"),
+            (3661, "PT1H1M1S"),
+            (HOUR + 1, "PT1H1S"),
+            (DAY + MINUTE, "PT24H1M"),
+        ];
+
+        for (secs, expected) in cases {
+            assert_eq!(iso8601_duration(secs).to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn iso8601_exact() {
+        let cases = [
+            (Duration::ZERO, "PT0S"),
+            (Duration::from_nanos(1), "PT0.000000001S"),
+            (Duration::from_millis(500), "PT0.5S"),
+            (Duration::from_millis(60_250), "PT1M0.25S"),
+            (Duration::new(3661, 120_000_000), "PT1H1M1.12S"),
+            (Duration::from_secs(3600), "PT1H"),
+        ];
+
+        for (duration, expected) in cases {
+            assert_eq!(iso8601_duration_exact(duration).to_string(), expected);
+        }
+    }
+}
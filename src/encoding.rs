@@ -0,0 +1,38 @@
+pub(crate) mod types {
+    #[allow(unused)]
+    use super::*;
+
+    /// See [`with_bom()`].
+    pub type WithBom<T> = crate::types::ConcatTuple<(char, T)>;
+}
+
+use types::*;
+
+/// Places a [byte order mark](https://en.wikipedia.org/wiki/Byte_order_mark)
+/// (`U+FEFF`) before a value.
+///
+/// When written as UTF-8, this is the bytes `EF BB BF`.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::with_bom("hola");
+/// assert_eq!(value.to_string(), "\u{FEFF}hola");
+/// ```
+pub fn with_bom<T>(value: T) -> WithBom<T> {
+    crate::concat_tuple(('\u{FEFF}', value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bom_bytes() {
+        let value = with_bom("abc").to_string();
+        assert_eq!(value.as_bytes(), b"\xEF\xBB\xBFabc");
+
+        let value = with_bom("").to_string();
+        assert_eq!(value.as_bytes(), b"\xEF\xBB\xBF");
+    }
+}
@@ -0,0 +1,271 @@
+use core::fmt::*;
+
+use crate::buf::InlineStr;
+
+pub(crate) mod types {
+    #[allow(unused)]
+    use super::*;
+
+    /// See [`escape_html()`].
+    #[derive(Clone, Copy)]
+    pub struct EscapeHtml<T> {
+        pub(super) value: T,
+    }
+
+    /// See [`escape_regex()`].
+    #[derive(Clone, Copy)]
+    pub struct EscapeRegex<T> {
+        pub(super) value: T,
+    }
+
+    /// See [`safe_filename()`].
+    #[derive(Clone, Copy)]
+    pub struct SafeFilename<T> {
+        pub(super) value: T,
+        pub(super) replacement: char,
+    }
+}
+
+use types::*;
+
+/// Escapes `&`, `<`, `>`, `"`, and `'` as HTML character references.
+///
+/// This makes a value safe to place within HTML text or a quoted attribute.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::escape_html("<a href=\"#\">Tom & Jerry</a>");
+/// assert_eq!(
+///     value.to_string(),
+///     "&lt;a href=&quot;#&quot;&gt;Tom &amp; Jerry&lt;/a&gt;",
+/// );
+/// ```
+pub fn escape_html<T>(value: T) -> EscapeHtml<T> {
+    EscapeHtml { value }
+}
+
+/// Escapes regular expression metacharacters with `\`.
+///
+/// This escapes the same characters as
+/// [`regex::escape()`](https://docs.rs/regex/latest/regex/fn.escape.html):
+/// `\`, `.`, `+`, `*`, `?`, `(`, `)`, `|`, `[`, `]`, `{`, `}`, `^`, `$`, `#`,
+/// `&`, `-`, and `~`.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::escape_regex("1.5+2");
+/// assert_eq!(value.to_string(), r"1\.5\+2");
+/// ```
+pub fn escape_regex<T>(value: T) -> EscapeRegex<T> {
+    EscapeRegex { value }
+}
+
+/// Replaces characters that are not allowed in Windows file names with
+/// `replacement`.
+///
+/// The replaced characters are `<`, `>`, `:`, `"`, `/`, `\`, `|`, `?`, `*`, and
+/// control characters `\0` through `\x1F`. Trailing dots and spaces, which
+/// Windows silently removes, are trimmed. Reserved names like `CON` and `NUL`
+/// are not changed.
+///
+/// Trailing dots and spaces are held back until another character is written.
+/// A run of more than 255 of them is written in full, since a name that long
+/// exceeds the Windows length limit regardless.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::safe_filename("report: 1/2?.", '_');
+/// assert_eq!(value.to_string(), "report_ 1_2_");
+/// ```
+pub fn safe_filename<T>(value: T, replacement: char) -> SafeFilename<T> {
+    SafeFilename { value, replacement }
+}
+
+impl<T: Display> Display for EscapeHtml<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        struct Writer<'a, 'b> {
+            f: &'a mut Formatter<'b>,
+        }
+
+        impl Write for Writer<'_, '_> {
+            fn write_str(&mut self, s: &str) -> Result {
+                let mut rest = s;
+
+                while let Some(i) = rest.find(&['&', '<', '>', '"', '\''][..]) {
+                    self.f.write_str(&rest[..i])?;
+                    self.f.write_str(match rest.as_bytes()[i] {
+                        b'&' => "&amp;",
+                        b'<' => "&lt;",
+                        b'>' => "&gt;",
+                        b'"' => "&quot;",
+                        _ => "&#39;",
+                    })?;
+                    rest = &rest[i + 1..];
+                }
+
+                self.f.write_str(rest)
+            }
+        }
+
+        write!(Writer { f }, "{}", self.value)
+    }
+}
+
+impl<T: Display> Display for EscapeRegex<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        struct Writer<'a, 'b> {
+            f: &'a mut Formatter<'b>,
+        }
+
+        impl Write for Writer<'_, '_> {
+            fn write_str(&mut self, s: &str) -> Result {
+                const META: &[char] = &[
+                    '\\', '.', '+', '*', '?', '(', ')', '|', '[', ']', '{',
+                    '}', '^', '$', '#', '&', '-', '~',
+                ];
+
+                let mut rest = s;
+
+                while let Some(i) = rest.find(META) {
+                    self.f.write_str(&rest[..i])?;
+                    self.f.write_char('\\')?;
+                    self.f.write_str(&rest[i..i + 1])?;
+                    rest = &rest[i + 1..];
+                }
+
+                self.f.write_str(rest)
+            }
+        }
+
+        write!(Writer { f }, "{}", self.value)
+    }
+}
+
+impl<T: Display> Display for SafeFilename<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        struct Writer<'a, 'b> {
+            f: &'a mut Formatter<'b>,
+            replacement: char,
+            pending: InlineStr<255>,
+            overflowed: bool,
+        }
+
+        impl Write for Writer<'_, '_> {
+            fn write_char(&mut self, c: char) -> Result {
+                let c = match c {
+                    '<'
+                    | '>'
+                    | ':'
+                    | '"'
+                    | '/'
+                    | '\\'
+                    | '|'
+                    | '?'
+                    | '*'
+                    | '\0'..='\x1F' => self.replacement,
+                    c => c,
+                };
+
+                if c != '.' && c != ' ' {
+                    self.f.write_str(self.pending.as_str())?;
+                    self.pending.clear();
+                    self.overflowed = false;
+                    return self.f.write_char(c);
+                }
+
+                if self.overflowed {
+                    return self.f.write_char(c);
+                }
+
+                if !self.pending.try_push(c) {
+                    self.f.write_str(self.pending.as_str())?;
+                    self.pending.clear();
+                    self.overflowed = true;
+                    self.f.write_char(c)?;
+                }
+                Ok(())
+            }
+
+            fn write_str(&mut self, s: &str) -> Result {
+                s.chars().try_for_each(|c| self.write_char(c))
+            }
+        }
+
+        let mut writer = Writer {
+            f,
+            replacement: self.replacement,
+            pending: InlineStr::new(),
+            overflowed: false,
+        };
+        write!(writer, "{}", self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn html() {
+        assert_eq!(escape_html("").to_string(), "");
+        assert_eq!(escape_html("plain").to_string(), "plain");
+        assert_eq!(
+            escape_html("<&>\"'").to_string(),
+            "&lt;&amp;&gt;&quot;&#39;",
+        );
+        assert_eq!(
+            escape_html(format_args!("{}b{}", '<', "&")).to_string(),
+            "&lt;b&amp;",
+        );
+    }
+
+    #[test]
+    fn regex() {
+        assert_eq!(escape_regex("").to_string(), "");
+        assert_eq!(escape_regex("plain text").to_string(), "plain text");
+        assert_eq!(
+            escape_regex(r"\.+*?()|[]{}^$#&-~").to_string(),
+            r"\\\.\+\*\?\(\)\|\[\]\{\}\^\$\#\&\-\~",
+        );
+        assert_eq!(
+            escape_regex(format_args!("a{}b", "(*)")).to_string(),
+            r"a\(\*\)b",
+        );
+    }
+
+    #[test]
+    fn filename() {
+        assert_eq!(safe_filename("", '_').to_string(), "");
+        assert_eq!(safe_filename("notes.txt", '_').to_string(), "notes.txt");
+        assert_eq!(
+            safe_filename("<>:\"/\\|?*\0\t\x1F", '_').to_string(),
+            "____________",
+        );
+        assert_eq!(
+            safe_filename("a<b>c:d\"e/f\\g|h?i*j\nk\x7F", '-').to_string(),
+            "a-b-c-d-e-f-g-h-i-j-k\x7F",
+        );
+    }
+
+    #[test]
+    fn filename_trailing() {
+        assert_eq!(safe_filename("a. .b. . ", '_').to_string(), "a. .b");
+        assert_eq!(safe_filename(" . ", '_').to_string(), "");
+        assert_eq!(
+            safe_filename(format_args!("a{}{}b{}", ". ", '.', " ."), '_')
+                .to_string(),
+            "a. .b",
+        );
+
+        // Replacements are trimmed too.
+        assert_eq!(safe_filename("a?*", '.').to_string(), "a");
+        assert_eq!(safe_filename("a?*b", '.').to_string(), "a..b");
+
+        // Runs beyond the buffer are written in full.
+        let name = format!("a{}", ".".repeat(300));
+        assert_eq!(safe_filename(&name, '_').to_string(), name);
+    }
+}
@@ -142,6 +142,93 @@ pub trait FmtIterator: Iterator + Sized {
         join_map_once(self, sep, f)
     }
 
+    /// Method for [`join_or()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fmty::FmtIterator;
+    ///
+    /// let value = ["hola", "mundo"].iter().fmt_join_or(" ", "(none)");
+    /// assert_eq!(value.to_string(), "hola mundo");
+    ///
+    /// let value = [""; 0].iter().fmt_join_or(" ", "(none)");
+    /// assert_eq!(value.to_string(), "(none)");
+    /// ```
+    fn fmt_join_or<S, E>(self, sep: S, empty: E) -> JoinOr<Self, S, E>
+    where
+        Self: Clone,
+    {
+        join_or(self, sep, empty)
+    }
+
+    /// Method for [`join_or_once()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fmty::FmtIterator;
+    ///
+    /// let value = [""; 0].iter().fmt_join_or_once(" ", "(none)");
+    /// assert_eq!(value.to_string(), "(none)");
+    ///
+    /// assert_eq!(value.to_string(), "");
+    /// ```
+    fn fmt_join_or_once<S, E>(
+        self,
+        sep: S,
+        empty: E,
+    ) -> JoinOrOnce<Self, S, E> {
+        join_or_once(self, sep, empty)
+    }
+
+    /// Method for [`join_map_or()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fmty::FmtIterator;
+    ///
+    /// let value = ["hola", "mundo"].iter().fmt_join_map_or(" ", "-", fmty::to_ascii_uppercase);
+    /// assert_eq!(value.to_string(), "HOLA MUNDO");
+    /// ```
+    fn fmt_join_map_or<S, E, R, F>(
+        self,
+        sep: S,
+        empty: E,
+        f: F,
+    ) -> JoinMapOr<Self, S, E, F>
+    where
+        Self: Clone,
+        F: Fn(Self::Item) -> R,
+    {
+        join_map_or(self, sep, empty, f)
+    }
+
+    /// Method for [`join_map_or_once()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fmty::FmtIterator;
+    ///
+    /// let value = ["hola", "mundo"].iter().fmt_join_map_or_once(" ", "-", fmty::to_ascii_uppercase);
+    /// assert_eq!(value.to_string(), "HOLA MUNDO");
+    ///
+    /// assert_eq!(value.to_string(), "");
+    /// ```
+    fn fmt_join_map_or_once<S, E, R, F>(
+        self,
+        sep: S,
+        empty: E,
+        f: F,
+    ) -> JoinMapOrOnce<Self, S, E, F>
+    where
+        F: Fn(Self::Item) -> R,
+    {
+        join_map_or_once(self, sep, empty, f)
+    }
+
     /// Method for [`csv()`].
     ///
     /// # Examples
@@ -55,7 +55,7 @@ use types::*;
 /// # let s: String = "hola".to_owned();
 /// let inner = fmt_with(|f| write!(f, "{s}"));
 /// let value = fmt_with(|f| write!(f, "{inner}"));
-/// # drop(value);
+/// # let _ = value;
 /// ```
 ///
 /// See [Rust issue #107623](https://github.com/rust-lang/rust/issues/107623)
@@ -0,0 +1,729 @@
+use core::fmt::*;
+
+use crate::{
+    measure::{char_len, max_char_len},
+    pad::write_fill,
+    types::Align,
+};
+
+pub(crate) mod types {
+    #[allow(unused)]
+    use super::*;
+
+    /// See [`aligned_kv()`].
+    #[derive(Clone, Copy)]
+    pub struct AlignedKv<I> {
+        pub(super) iter: I,
+    }
+
+    /// See [`grid_with_header()`].
+    #[derive(Clone, Copy)]
+    pub struct GridWithHeader<H, I, const N: usize> {
+        pub(super) header: [H; N],
+        pub(super) rows: I,
+    }
+
+    /// See [`columnate()`].
+    #[derive(Clone, Copy)]
+    pub struct Columnate<I> {
+        pub(super) iter: I,
+        pub(super) total_width: usize,
+        pub(super) gap: usize,
+    }
+
+    /// See [`def_list()`].
+    #[derive(Clone, Copy)]
+    pub struct DefList<I> {
+        pub(super) iter: I,
+        pub(super) gap: usize,
+    }
+
+    /// See [`table()`].
+    #[derive(Clone, Copy)]
+    pub struct Table<'a, I> {
+        pub(super) rows: I,
+        pub(super) aligns: &'a [Align],
+    }
+
+    /// See [`rows()`].
+    #[derive(Clone, Copy)]
+    pub struct Rows<I, C, S> {
+        pub(super) iter: I,
+        pub(super) cols: C,
+        pub(super) sep: S,
+    }
+}
+
+use types::*;
+
+/// Writes key-value pairs as `key = value` lines with keys padded to the widest
+/// key.
+///
+/// Width is measured in [`char`]s. Because the width of every key must be
+/// known before writing the first, each key is formatted twice.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::aligned_kv([("name", "fmty"), ("version", "0.1.1")]);
+/// assert_eq!(value.to_string(), "name    = fmty\nversion = 0.1.1");
+/// ```
+pub fn aligned_kv<I, K, V>(pairs: I) -> AlignedKv<I::IntoIter>
+where
+    I: IntoIterator<Item = (K, V)>,
+    I::IntoIter: Clone,
+{
+    AlignedKv { iter: pairs.into_iter() }
+}
+
+/// Writes a header and rows as columns padded to the widest cell, with a line
+/// between the header and rows.
+///
+/// Cells are separated by ` │ `, and the line below the header is made of `─`
+/// with `┼` where it crosses a column separator. The last column is not
+/// padded.
+///
+/// The number of columns is fixed by `N` so that column widths can be measured
+/// without allocating. Width is measured in [`char`]s. Because the width of
+/// every column must be known before writing the first row, each cell is
+/// formatted twice.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::grid_with_header(
+///     ["name", "qty"],
+///     [["apple", "3"], ["fig", "12"]],
+/// );
+///
+/// assert_eq!(value.to_string(), "\
+/// name  │ qty
+/// ──────┼────
+/// apple │ 3
+/// fig   │ 12");
+/// ```
+pub fn grid_with_header<H, I, T, const N: usize>(
+    header: [H; N],
+    rows: I,
+) -> GridWithHeader<H, I::IntoIter, N>
+where
+    I: IntoIterator<Item = [T; N]>,
+    I::IntoIter: Clone,
+{
+    GridWithHeader { header, rows: rows.into_iter() }
+}
+
+/// Writes [`Iterator`] items row by row in as many equal-width columns as fit
+/// within `total_width`, like `ls`.
+///
+/// Each column is as wide as the widest item, and columns are separated by
+/// `gap` spaces. If not even two columns fit, each item is written on its own
+/// line.
+///
+/// Width is measured in [`char`]s. Because the width of every item must be
+/// known before writing the first, each item is formatted twice.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::columnate(["a", "bb", "ccc", "d", "e"], 13, 2);
+/// assert_eq!(value.to_string(), "a    bb   ccc\nd    e");
+/// ```
+pub fn columnate<I>(
+    iter: I,
+    total_width: usize,
+    gap: usize,
+) -> Columnate<I::IntoIter>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+{
+    Columnate { iter: iter.into_iter(), total_width, gap }
+}
+
+/// Writes term-definition pairs as two columns, like the options in `--help`
+/// output.
+///
+/// Terms are padded to the widest term, followed by `gap` spaces and the
+/// definition. Lines after the first in a definition are indented to line up
+/// with the definition column.
+///
+/// Width is measured in [`char`]s. Because the width of every term must be
+/// known before writing the first, each term is formatted twice.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::def_list(
+///     [("-h, --help", "Print help"), ("-q", "Be quiet\nSuppresses warnings")],
+///     2,
+/// );
+///
+/// assert_eq!(value.to_string(), "\
+/// -h, --help  Print help
+/// -q          Be quiet
+///             Suppresses warnings");
+/// ```
+pub fn def_list<I, T, D>(pairs: I, gap: usize) -> DefList<I::IntoIter>
+where
+    I: IntoIterator<Item = (T, D)>,
+    I::IntoIter: Clone,
+{
+    DefList { iter: pairs.into_iter(), gap }
+}
+
+/// Writes rows as columns padded to the widest cell, with each column aligned
+/// by `aligns`.
+///
+/// Cells are separated by two spaces. Columns without an entry in `aligns` are
+/// left-aligned. The last column is not padded after its cells.
+///
+/// The number of columns is fixed by `N` so that column widths can be measured
+/// without allocating. Width is measured in [`char`]s. Because the width of
+/// every column must be known before writing the first row, each cell is
+/// formatted twice.
+///
+/// # Examples
+///
+/// ```
+/// use fmty::types::Align;
+///
+/// let value = fmty::table(
+///     [["apple", "3"], ["fig", "12"]],
+///     &[Align::Left, Align::Right],
+/// );
+///
+/// assert_eq!(value.to_string(), "apple   3\nfig    12");
+/// ```
+pub fn table<I, T, const N: usize>(
+    rows: I,
+    aligns: &[Align],
+) -> Table<'_, I::IntoIter>
+where
+    I: IntoIterator<Item = [T; N]>,
+    I::IntoIter: Clone,
+{
+    Table { rows: rows.into_iter(), aligns }
+}
+
+/// Writes [`Iterator`] items as rows of left-aligned columns, where each
+/// column is produced by a closure in the `cols` tuple.
+///
+/// This lets one data type drive a table without building each cell up front.
+/// Each closure is given a clone of the item. Columns are padded to the widest
+/// cell and separated by `sep`. The last column is not padded after its cells.
+///
+/// Tuples of 2 and 3 closures are supported. Width is measured in [`char`]s.
+/// Because the width of every column must be known before writing the first
+/// row, each cell is formatted twice.
+///
+/// # Examples
+///
+/// ```
+/// struct Crate {
+///     name: &'static str,
+///     version: &'static str,
+///     downloads: u32,
+/// }
+///
+/// let crates = [
+///     Crate { name: "fmty", version: "0.1.1", downloads: 1200 },
+///     Crate { name: "divan", version: "0.1.14", downloads: 95 },
+/// ];
+///
+/// let value = fmty::rows(
+///     &crates,
+///     (
+///         |c: &Crate| c.name,
+///         |c: &Crate| c.version,
+///         |c: &Crate| c.downloads,
+///     ),
+///     "  ",
+/// );
+///
+/// assert_eq!(value.to_string(), "\
+/// fmty   0.1.1   1200
+/// divan  0.1.14  95");
+/// ```
+pub fn rows<I, C, S>(data: I, cols: C, sep: S) -> Rows<I::IntoIter, C, S>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+    I::Item: Clone,
+{
+    Rows { iter: data.into_iter(), cols, sep }
+}
+
+/// Widens each column width to fit `cells`.
+fn widen<T: Display>(widths: &mut [usize], cells: &[T]) -> Result {
+    for (width, cell) in widths.iter_mut().zip(cells) {
+        *width = (*width).max(char_len(cell)?);
+    }
+    Ok(())
+}
+
+/// Writes cells separated by ` │ `, padded to `widths`.
+fn write_row<T: Display>(
+    f: &mut Formatter,
+    cells: &[T],
+    widths: &[usize],
+) -> Result {
+    for (i, (cell, &width)) in cells.iter().zip(widths).enumerate() {
+        write_cell(f, i, cells.len(), &" │ ", cell, width)?;
+    }
+    Ok(())
+}
+
+/// Writes the cell in column `i` of `n`, preceded by `sep` and padded to
+/// `width` if not the last column.
+fn write_cell<T: Display, S: Display>(
+    f: &mut Formatter,
+    i: usize,
+    n: usize,
+    sep: &S,
+    cell: &T,
+    width: usize,
+) -> Result {
+    if i != 0 {
+        write!(f, "{}", sep)?;
+    }
+    write!(f, "{}", cell)?;
+
+    if i + 1 != n {
+        write_fill(f, ' ', width.saturating_sub(char_len(cell)?))?;
+    }
+    Ok(())
+}
+
+impl<I, K, V> Display for AlignedKv<I>
+where
+    I: Iterator<Item = (K, V)> + Clone,
+    K: Display,
+    V: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let width = max_char_len(self.iter.clone().map(|(key, _)| key))?;
+
+        for (i, (key, value)) in self.iter.clone().enumerate() {
+            if i != 0 {
+                f.write_char('\n')?;
+            }
+            write!(f, "{}", key)?;
+            write_fill(f, ' ', width.saturating_sub(char_len(&key)?))?;
+            write!(f, " = {}", value)?;
+        }
+        Ok(())
+    }
+}
+
+impl<H, I, T, const N: usize> Display for GridWithHeader<H, I, N>
+where
+    H: Display,
+    I: Iterator<Item = [T; N]> + Clone,
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let mut widths = [0; N];
+        widen(&mut widths, &self.header)?;
+        for row in self.rows.clone() {
+            widen(&mut widths, &row)?;
+        }
+
+        write_row(f, &self.header, &widths)?;
+        f.write_char('\n')?;
+
+        for (i, &width) in widths.iter().enumerate() {
+            if i != 0 {
+                f.write_char('┼')?;
+            }
+
+            // Account for the spaces around each `│`.
+            let pad = if N == 1 {
+                0
+            } else if i == 0 || i + 1 == N {
+                1
+            } else {
+                2
+            };
+            write_fill(f, '─', width + pad)?;
+        }
+
+        for row in self.rows.clone() {
+            f.write_char('\n')?;
+            write_row(f, &row, &widths)?;
+        }
+        Ok(())
+    }
+}
+
+impl<I> Display for Columnate<I>
+where
+    I: Iterator + Clone,
+    I::Item: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let width = max_char_len(self.iter.clone())?;
+
+        // `n` columns need `n * width + (n - 1) * gap` chars.
+        let columns = self
+            .total_width
+            .saturating_add(self.gap)
+            .checked_div(width.saturating_add(self.gap))
+            .unwrap_or(usize::MAX)
+            .max(1);
+
+        let mut iter = self.iter.clone().enumerate().peekable();
+
+        while let Some((i, item)) = iter.next() {
+            write!(f, "{}", item)?;
+
+            if iter.peek().is_none() {
+                break;
+            }
+
+            if (i + 1) % columns == 0 {
+                f.write_char('\n')?;
+            } else {
+                write_fill(
+                    f,
+                    ' ',
+                    width.saturating_sub(char_len(&item)?) + self.gap,
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<I, T, D> Display for DefList<I>
+where
+    I: Iterator<Item = (T, D)> + Clone,
+    T: Display,
+    D: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        /// Indents lines after the first, except for empty lines.
+        struct Writer<'a, 'b> {
+            f: &'a mut Formatter<'b>,
+            indent: usize,
+            at_line_start: bool,
+        }
+
+        impl Write for Writer<'_, '_> {
+            fn write_str(&mut self, s: &str) -> Result {
+                for (i, line) in s.split('\n').enumerate() {
+                    if i != 0 {
+                        self.f.write_char('\n')?;
+                        self.at_line_start = true;
+                    }
+
+                    if !line.is_empty() {
+                        if self.at_line_start {
+                            write_fill(self.f, ' ', self.indent)?;
+                            self.at_line_start = false;
+                        }
+                        self.f.write_str(line)?;
+                    }
+                }
+                Ok(())
+            }
+        }
+
+        let width = max_char_len(self.iter.clone().map(|(term, _)| term))?;
+
+        for (i, (term, def)) in self.iter.clone().enumerate() {
+            if i != 0 {
+                f.write_char('\n')?;
+            }
+            write!(f, "{}", term)?;
+            write_fill(
+                f,
+                ' ',
+                width.saturating_sub(char_len(&term)?) + self.gap,
+            )?;
+
+            let indent = width + self.gap;
+            write!(Writer { f, indent, at_line_start: false }, "{}", def)?;
+        }
+        Ok(())
+    }
+}
+
+impl<I, T, const N: usize> Display for Table<'_, I>
+where
+    I: Iterator<Item = [T; N]> + Clone,
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let mut widths = [0; N];
+        for row in self.rows.clone() {
+            widen(&mut widths, &row)?;
+        }
+
+        for (i, row) in self.rows.clone().enumerate() {
+            if i != 0 {
+                f.write_char('\n')?;
+            }
+
+            for (j, (cell, &width)) in row.iter().zip(&widths).enumerate() {
+                if j != 0 {
+                    f.write_str("  ")?;
+                }
+
+                let align = self.aligns.get(j).copied().unwrap_or(Align::Left);
+                let (left, right) =
+                    align.split(width.saturating_sub(char_len(cell)?));
+
+                write_fill(f, ' ', left)?;
+                write!(f, "{}", cell)?;
+                if j + 1 != N {
+                    write_fill(f, ' ', right)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Implements `Display` for `Rows<I, (F, ...), S>`.
+macro_rules! impl_rows {
+    ($n:literal; $($f:ident $r:ident $i:tt),+) => {
+        impl<I, S, $($f, $r),+> Display for Rows<I, ($($f,)+), S>
+        where
+            I: Iterator + Clone,
+            I::Item: Clone,
+            S: Display,
+            $($f: Fn(I::Item) -> $r, $r: Display,)+
+        {
+            fn fmt(&self, f: &mut Formatter) -> Result {
+                let mut widths = [0; $n];
+                for row in self.iter.clone() {
+                    $(
+                        let len = char_len(&(self.cols.$i)(row.clone()))?;
+                        widths[$i] = widths[$i].max(len);
+                    )+
+                }
+
+                for (i, row) in self.iter.clone().enumerate() {
+                    if i != 0 {
+                        f.write_char('\n')?;
+                    }
+
+                    $(
+                        let cell = (self.cols.$i)(row.clone());
+                        write_cell(f, $i, $n, &self.sep, &cell, widths[$i])?;
+                    )+
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_rows!(2; F0 R0 0, F1 R1 1);
+impl_rows!(3; F0 R0 0, F1 R1 1, F2 R2 2);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kv() {
+        let value = aligned_kv([("a", 1), ("ccc", 3), ("bb", 2)]);
+        assert_eq!(value.to_string(), "a   = 1\nccc = 3\nbb  = 2");
+
+        let value = aligned_kv([("", ""); 0]);
+        assert_eq!(value.to_string(), "");
+    }
+
+    #[test]
+    fn header() {
+        let value = grid_with_header(
+            ["a", "bb", "c"],
+            [["xxx", "y", "zz"], ["", "", ""]],
+        );
+        let expected = core::concat!(
+            "a   │ bb │ c\n",
+            "────┼────┼───\n",
+            "xxx │ y  │ zz\n",
+            "    │    │ ",
+        );
+
+        assert_eq!(value.to_string(), expected);
+
+        // Each `┼` lines up with a `│`.
+        let lines: Vec<Vec<usize>> = value
+            .to_string()
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .enumerate()
+                    .filter(|&(_, c)| c == '│' || c == '┼')
+                    .map(|(i, _)| i)
+                    .collect()
+            })
+            .collect();
+        assert!(lines.iter().all(|line| *line == lines[0]));
+    }
+
+    #[test]
+    fn header_edges() {
+        let value = grid_with_header(["only"], [["a"], ["bbbbbb"]]);
+        assert_eq!(value.to_string(), "only\n──────\na\nbbbbbb");
+
+        let value = grid_with_header(["a", "b"], [["", ""]; 0]);
+        assert_eq!(value.to_string(), "a │ b\n──┼──");
+    }
+
+    #[test]
+    fn columns() {
+        let words = ["ant", "bee", "cat", "dog", "eel", "fox", "gnu"];
+
+        let value = columnate(words, 20, 2);
+        assert_eq!(value.to_string(), "ant  bee  cat  dog\neel  fox  gnu");
+
+        let value = columnate(words, 17, 2);
+        assert_eq!(value.to_string(), "ant  bee  cat\ndog  eel  fox\ngnu");
+
+        let value = columnate(["a", "bbbbbb", "c"], 20, 1);
+        assert_eq!(value.to_string(), "a      bbbbbb c");
+    }
+
+    #[test]
+    fn columns_narrow() {
+        let value = columnate(["toolong", "x"], 3, 2);
+        assert_eq!(value.to_string(), "toolong\nx");
+
+        let value = columnate(["", ""], 0, 0);
+        assert_eq!(value.to_string(), "");
+
+        let value = columnate([""; 0], 10, 2);
+        assert_eq!(value.to_string(), "");
+    }
+
+    #[test]
+    fn columns_unbounded() {
+        let value = columnate(["a", "b"], usize::MAX, 2);
+        assert_eq!(value.to_string(), "a  b");
+
+        let value = columnate(["a", "b"], 0, usize::MAX);
+        assert_eq!(
+            value.to_string(),
+            "a
+b"
+        );
+
+        let value = columnate(["a", "b"], usize::MAX, usize::MAX);
+        assert_eq!(
+            value.to_string(),
+            "a
+b"
+        );
+    }
+
+    #[test]
+    fn definitions() {
+        let value = def_list(
+            [
+                ("--color <WHEN>", "When to use color\n\n[default: auto]"),
+                ("-v", "Verbose"),
+            ],
+            3,
+        );
+        let expected = core::concat!(
+            "--color <WHEN>   When to use color\n",
+            "\n",
+            "                 [default: auto]\n",
+            "-v               Verbose",
+        );
+        assert_eq!(value.to_string(), expected);
+
+        let def = crate::concat_tuple(("x\n", 'y', '\n', "z"));
+        assert_eq!(def_list([("a", def)], 1).to_string(), "a x\n  y\n  z");
+
+        let value = def_list([("", ""); 0], 2);
+        assert_eq!(value.to_string(), "");
+    }
+
+    #[test]
+    fn tables() {
+        use Align::*;
+
+        let rows = [
+            ["name", "qty", "price"],
+            ["apple", "3", "1.25"],
+            ["fig", "12", "0.5"],
+        ];
+
+        let value = table(rows, &[Left, Right, Right]);
+        let expected = core::concat!(
+            "name   qty  price\n",
+            "apple    3   1.25\n",
+            "fig     12    0.5",
+        );
+        assert_eq!(value.to_string(), expected);
+
+        // Missing aligns default to left.
+        let value = table(rows, &[Right]);
+        let expected = core::concat!(
+            " name  qty  price\n",
+            "apple  3    1.25\n",
+            "  fig  12   0.5",
+        );
+        assert_eq!(value.to_string(), expected);
+
+        let value = table([["a", "b"], ["ccccc", "d"]], &[Center]);
+        assert_eq!(value.to_string(), "  a    b\nccccc  d");
+
+        let value = table([["", ""]; 0], &[]);
+        assert_eq!(value.to_string(), "");
+    }
+
+    #[test]
+    fn closure_rows() {
+        let data = [("apple", 3, 1.25), ("fig", 12, 0.5), ("kiwi", 100, 10.0)];
+
+        let value = rows(
+            data,
+            (
+                |(name, _, _): (&'static str, i32, f64)| name,
+                |(_, qty, _): (&'static str, i32, f64)| qty,
+                |(_, _, price): (&'static str, i32, f64)| price,
+            ),
+            " | ",
+        );
+        let expected = core::concat!(
+            "apple | 3   | 1.25\n",
+            "fig   | 12  | 0.5\n",
+            "kiwi  | 100 | 10",
+        );
+        assert_eq!(value.to_string(), expected);
+
+        let value = rows(
+            ["a", "bbb", ""],
+            (|s: &'static str| s, |s: &'static str| s.len()),
+            ' ',
+        );
+        assert_eq!(value.to_string(), "a   1\nbbb 3\n    0");
+
+        let value =
+            rows([""; 0], (|s: &'static str| s, |s: &'static str| s), ' ');
+        assert_eq!(value.to_string(), "");
+    }
+
+    #[test]
+    fn closure_rows_changing() {
+        use core::cell::Cell;
+
+        // Cells wider than when measured are written without padding.
+        let n = Cell::new(1);
+        let grow = |_: u8| {
+            let value = n.get();
+            n.set(value * 10);
+            value
+        };
+
+        let value = rows([0, 0], (grow, |_: u8| 'x'), ' ');
+        assert_eq!(value.to_string(), "100 x\n1000 x");
+    }
+}
@@ -0,0 +1,114 @@
+use core::fmt::*;
+
+use crate::escape_html;
+
+pub(crate) mod types {
+    #[allow(unused)]
+    use super::*;
+
+    /// See [`html_ul()`], [`html_ol()`].
+    #[derive(Clone, Copy)]
+    pub struct HtmlList<I> {
+        pub(super) iter: I,
+        pub(super) ordered: bool,
+    }
+}
+
+use types::*;
+
+/// Writes [`Iterator`] items as an HTML unordered list (`<ul>`).
+///
+/// Each item is escaped with [`escape_html()`]. The alternate flag (`{:#}`)
+/// places each item on its own indented line.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::html_ul(["a", "b & c"]);
+/// assert_eq!(value.to_string(), "<ul><li>a</li><li>b &amp; c</li></ul>");
+/// assert_eq!(
+///     format!("{value:#}"),
+///     "<ul>\n  <li>a</li>\n  <li>b &amp; c</li>\n</ul>",
+/// );
+/// ```
+pub fn html_ul<I>(iter: I) -> HtmlList<I::IntoIter>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+{
+    HtmlList { iter: iter.into_iter(), ordered: false }
+}
+
+/// Writes [`Iterator`] items as an HTML ordered list (`<ol>`).
+///
+/// Each item is escaped with [`escape_html()`]. The alternate flag (`{:#}`)
+/// places each item on its own indented line.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::html_ol(["a", "b"]);
+/// assert_eq!(value.to_string(), "<ol><li>a</li><li>b</li></ol>");
+/// ```
+pub fn html_ol<I>(iter: I) -> HtmlList<I::IntoIter>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+{
+    HtmlList { iter: iter.into_iter(), ordered: true }
+}
+
+impl<I> Display for HtmlList<I>
+where
+    I: Iterator + Clone,
+    I::Item: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let tag = if self.ordered { "ol" } else { "ul" };
+        let (indent, newline) =
+            if f.alternate() { ("  ", "\n") } else { ("", "") };
+
+        write!(f, "<{}>{}", tag, newline)?;
+
+        for item in self.iter.clone() {
+            write!(f, "{}<li>{}</li>{}", indent, escape_html(item), newline)?;
+        }
+
+        write!(f, "</{}>", tag)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compact() {
+        let items = ["<b>", "x"];
+
+        assert_eq!(
+            html_ul(items).to_string(),
+            "<ul><li>&lt;b&gt;</li><li>x</li></ul>",
+        );
+        assert_eq!(
+            html_ol(items).to_string(),
+            "<ol><li>&lt;b&gt;</li><li>x</li></ol>",
+        );
+        assert_eq!(html_ul([""; 0]).to_string(), "<ul></ul>");
+    }
+
+    #[test]
+    fn pretty() {
+        let items = ["<b>", "x"];
+
+        assert_eq!(
+            format!("{:#}", html_ul(items)),
+            "<ul>\n  <li>&lt;b&gt;</li>\n  <li>x</li>\n</ul>",
+        );
+        assert_eq!(
+            format!("{:#}", html_ol(items)),
+            "<ol>\n  <li>&lt;b&gt;</li>\n  <li>x</li>\n</ol>",
+        );
+        assert_eq!(format!("{:#}", html_ol([""; 0])), "<ol>\n</ol>");
+    }
+}
@@ -0,0 +1,104 @@
+use core::fmt::*;
+
+pub(crate) mod types {
+    #[allow(unused)]
+    use super::*;
+
+    /// See [`call_stack()`].
+    #[derive(Clone, Copy)]
+    pub struct CallStack<I, S> {
+        pub(super) iter: I,
+        pub(super) indent: S,
+    }
+}
+
+use types::*;
+
+/// Writes `(depth, label)` frames on separate lines, each indented by `indent`
+/// once per level of depth.
+///
+/// This is useful for profiler output, where each frame is a call made by the
+/// nearest previous frame with a smaller depth. Depths are not checked, so a
+/// frame may be more than one level deeper than the one before it.
+///
+/// Frames are separated by `\n`, without a trailing newline.
+///
+/// # Examples
+///
+/// ```
+/// let frames = [(0, "main"), (1, "run"), (2, "parse"), (1, "exit")];
+///
+/// let value = fmty::call_stack(frames, "  ");
+/// assert_eq!(value.to_string(), "main\n  run\n    parse\n  exit");
+/// ```
+pub fn call_stack<I, S, L>(frames: I, indent: S) -> CallStack<I::IntoIter, S>
+where
+    I: IntoIterator<Item = (usize, L)>,
+    I::IntoIter: Clone,
+{
+    CallStack { iter: frames.into_iter(), indent }
+}
+
+impl<I, S, L> Display for CallStack<I, S>
+where
+    I: Iterator<Item = (usize, L)> + Clone,
+    S: Display,
+    L: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        for (i, (depth, label)) in self.iter.clone().enumerate() {
+            if i != 0 {
+                f.write_char('\n')?;
+            }
+            for _ in 0..depth {
+                write!(f, "{}", self.indent)?;
+            }
+            write!(f, "{}", label)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn three_levels() {
+        let frames = [
+            (0, "main"),
+            (1, "handle_request"),
+            (2, "parse_headers"),
+            (2, "route"),
+            (1, "flush"),
+        ];
+
+        assert_eq!(
+            call_stack(frames, "  ").to_string(),
+            core::concat!(
+                "main\n",
+                "  handle_request\n",
+                "    parse_headers\n",
+                "    route\n",
+                "  flush",
+            ),
+        );
+        assert_eq!(
+            call_stack(frames, "│ ").to_string(),
+            core::concat!(
+                "main\n",
+                "│ handle_request\n",
+                "│ │ parse_headers\n",
+                "│ │ route\n",
+                "│ flush",
+            ),
+        );
+    }
+
+    #[test]
+    fn edge_cases() {
+        assert_eq!(call_stack([(0, ""); 0], "  ").to_string(), "");
+        assert_eq!(call_stack([(3, 'x')], "-").to_string(), "---x");
+        assert_eq!(call_stack([(2, 'a'), (0, 'b')], "").to_string(), "a\nb");
+    }
+}
@@ -1,6 +1,10 @@
 use core::fmt::*;
 
-use crate::once::Once;
+use crate::{
+    measure::{byte_len, is_empty},
+    once::Once,
+    truncate::compact_unless_alternate,
+};
 
 pub(crate) mod types {
     #[allow(unused)]
@@ -48,6 +52,200 @@ pub(crate) mod types {
 
     /// See [`csv_tuple()`].
     pub type CsvTuple<T> = JoinTuple<T, &'static str>;
+
+    /// See [`join_names()`].
+    #[derive(Clone, Copy)]
+    pub struct JoinNames<I> {
+        pub(super) iter: I,
+        pub(super) max: usize,
+    }
+
+    /// See [`join_or()`].
+    #[derive(Clone, Copy)]
+    pub struct JoinOr<I, S, E> {
+        pub(super) iter: I,
+        pub(super) sep: S,
+        pub(super) empty: E,
+    }
+
+    /// See [`join_or_once()`].
+    pub type JoinOrOnce<I, S, E> = JoinOr<Once<I>, S, E>;
+
+    /// See [`join_map_or()`].
+    #[derive(Clone, Copy)]
+    pub struct JoinMapOr<I, S, E, F> {
+        pub(super) iter: I,
+        pub(super) sep: S,
+        pub(super) empty: E,
+        pub(super) map: F,
+    }
+
+    /// See [`join_map_or_once()`].
+    pub type JoinMapOrOnce<I, S, E, F> = JoinMapOr<Once<I>, S, E, F>;
+
+    /// See [`join_budget()`].
+    #[derive(Clone, Copy)]
+    pub struct JoinBudget<I, S, O> {
+        pub(super) iter: I,
+        pub(super) sep: S,
+        pub(super) max_bytes: usize,
+        pub(super) overflow: O,
+    }
+
+    /// See [`join_prefixed()`].
+    #[derive(Clone, Copy)]
+    pub struct JoinPrefixed<L, I, S> {
+        pub(super) labels: L,
+        pub(super) iter: I,
+        pub(super) sep: S,
+    }
+
+    /// See [`group_runs()`].
+    #[derive(Clone, Copy)]
+    pub struct GroupRuns<I, S, F, G> {
+        pub(super) iter: I,
+        pub(super) sep: S,
+        pub(super) key: F,
+        pub(super) group_sep: G,
+    }
+
+    /// See [`join_natural_sorted()`].
+    #[cfg(feature = "alloc")]
+    #[derive(Clone, Copy)]
+    pub struct JoinNaturalSorted<I, S> {
+        pub(super) iter: I,
+        pub(super) sep: S,
+    }
+
+    /// See [`join_preview()`].
+    #[derive(Clone, Copy)]
+    pub struct JoinPreview<I, S, F> {
+        pub(super) iter: I,
+        pub(super) sep: S,
+        pub(super) max: usize,
+        pub(super) more: F,
+    }
+
+    /// See [`join_quoted()`].
+    pub type JoinQuoted<I, S, F> = JoinMap<I, S, F>;
+
+    /// See [`join_compact()`].
+    #[derive(Clone, Copy)]
+    pub struct JoinCompact<I, S> {
+        pub(super) iter: I,
+        pub(super) sep: S,
+    }
+
+    /// See [`join_every()`].
+    #[derive(Clone, Copy)]
+    pub struct JoinEvery<I, S, B> {
+        pub(super) iter: I,
+        pub(super) sep: S,
+        pub(super) every: usize,
+        pub(super) big_sep: B,
+    }
+
+    /// See [`fill_sparse()`].
+    #[derive(Clone, Copy)]
+    pub struct FillSparse<I, G, S> {
+        pub(super) iter: I,
+        pub(super) gap_fill: G,
+        pub(super) sep: S,
+    }
+
+    /// See [`join_rev_collect()`].
+    #[cfg(feature = "alloc")]
+    #[derive(Clone, Copy)]
+    pub struct JoinRevCollect<I, S> {
+        pub(super) iter: I,
+        pub(super) sep: S,
+    }
+
+    /// See [`join_ranges()`].
+    #[derive(Clone, Copy)]
+    pub struct JoinRanges<I, S> {
+        pub(super) iter: I,
+        pub(super) sep: S,
+    }
+
+    /// See [`join_results()`].
+    #[derive(Clone, Copy)]
+    pub struct JoinResults<I, S, F> {
+        pub(super) iter: I,
+        pub(super) ok_sep: S,
+        pub(super) err_summary: F,
+    }
+
+    /// See [`join_strict()`].
+    #[derive(Clone, Copy)]
+    pub struct JoinStrict<I, S> {
+        pub(super) iter: I,
+        pub(super) sep: S,
+    }
+
+    /// See [`join_skip_empty_edges()`].
+    #[derive(Clone, Copy)]
+    pub struct JoinSkipEmptyEdges<I, S> {
+        pub(super) iter: I,
+        pub(super) sep: S,
+    }
+
+    /// See [`list_english()`].
+    pub type ListEnglish<I> = ConjunctionList<I, &'static str>;
+
+    /// See [`conjunction_list()`].
+    #[derive(Clone, Copy)]
+    pub struct ConjunctionList<I, C> {
+        pub(super) iter: I,
+        pub(super) conj: C,
+        pub(super) oxford: bool,
+    }
+
+    /// See [`join_map_truncate()`].
+    #[derive(Clone, Copy)]
+    pub struct JoinMapTruncate<I, S> {
+        pub(super) iter: I,
+        pub(super) sep: S,
+        pub(super) item_max: usize,
+    }
+
+    /// See [`join_alt_sep()`].
+    #[derive(Clone, Copy)]
+    pub struct JoinAltSep<I, A, B> {
+        pub(super) iter: I,
+        pub(super) sep_a: A,
+        pub(super) sep_b: B,
+    }
+
+    /// See [`breadcrumb()`], [`breadcrumb_sep()`].
+    pub type Breadcrumb<I, S = &'static str> = Join<I, S>;
+
+    /// See [`join_skip()`].
+    #[derive(Clone, Copy)]
+    pub struct JoinSkip<I, S, F> {
+        pub(super) iter: I,
+        pub(super) sep: S,
+        pub(super) skip: F,
+    }
+
+    /// See [`join_head_tail()`].
+    #[derive(Clone, Copy)]
+    pub struct JoinHeadTail<I, S, E> {
+        pub(super) iter: I,
+        pub(super) sep: S,
+        pub(super) head: usize,
+        pub(super) tail: usize,
+        pub(super) ellipsis: E,
+    }
+
+    /// See [`join_paged()`].
+    #[derive(Clone, Copy)]
+    pub struct JoinPaged<I, S, F> {
+        pub(super) iter: I,
+        pub(super) per_page: usize,
+        pub(super) item_sep: S,
+        pub(super) page_sep: F,
+    }
 }
 
 use types::*;
@@ -260,169 +458,1700 @@ pub fn csv_tuple<T>(tuple: T) -> CsvTuple<T> {
     join_tuple(tuple, ", ")
 }
 
-impl<I, S> Debug for Join<I, S>
+/// Concatenates up to `max` [`Iterator`] items as an English list, followed by
+/// the number of remaining items.
+///
+/// Items are separated by `, `, except that the last two are separated by
+/// ` and ` if every item is written. Otherwise, ` and N others` is written
+/// after the first `max` items.
+///
+/// # Examples
+///
+/// ```
+/// let names = ["Alice", "Bob", "Carol", "Dave", "Erin"];
+///
+/// let value = fmty::join_names(names, 2);
+/// assert_eq!(value.to_string(), "Alice, Bob and 3 others");
+///
+/// let value = fmty::join_names(&names[..2], 2);
+/// assert_eq!(value.to_string(), "Alice and Bob");
+/// ```
+pub fn join_names<I>(iter: I, max: usize) -> JoinNames<I::IntoIter>
 where
-    I: Iterator + Clone,
-    I::Item: Debug,
-    S: Display,
+    I: IntoIterator,
+    I::IntoIter: ExactSizeIterator + Clone,
 {
-    fn fmt(&self, f: &mut Formatter) -> Result {
-        let mut iter = self.iter.clone();
-
-        if let Some(item) = iter.next() {
-            write!(f, "{:?}", item)?;
-        }
-
-        for item in iter {
-            write!(f, "{}{:?}", self.sep, item)?;
-        }
-
-        Ok(())
-    }
+    JoinNames { iter: iter.into_iter(), max }
 }
 
-impl<I, S> Display for Join<I, S>
+/// Concatenates [`Iterator`] items with a separator between each, or writes
+/// `empty` if there are no items.
+///
+/// If [`Clone`] for the [`Iterator`] is too expensive, consider using
+/// [`join_or_once()`].
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::join_or(["hola", "mundo"], " ", "(none)");
+/// assert_eq!(value.to_string(), "hola mundo");
+///
+/// let value = fmty::join_or([""; 0], " ", "(none)");
+/// assert_eq!(value.to_string(), "(none)");
+/// ```
+pub fn join_or<I, S, E>(iter: I, sep: S, empty: E) -> JoinOr<I::IntoIter, S, E>
 where
-    I: Iterator + Clone,
-    I::Item: Display,
-    S: Display,
+    I: IntoIterator,
+    I::IntoIter: Clone,
 {
-    fn fmt(&self, f: &mut Formatter) -> Result {
-        let mut iter = self.iter.clone();
-
-        if let Some(item) = iter.next() {
-            write!(f, "{}", item)?;
-        }
-
-        for item in iter {
-            write!(f, "{}{}", self.sep, item)?;
-        }
-
-        Ok(())
-    }
+    JoinOr { iter: iter.into_iter(), sep, empty }
 }
 
-impl<I, S> Debug for JoinOnce<I, S>
+/// Concatenates [`Iterator`] items with a separator between each, or writes
+/// `empty` if there are no items, at most once.
+///
+/// This is a non-[`Clone`] alternative to [`join_or()`]. It uses interior
+/// mutability to take ownership of the iterator in the first call to
+/// [`Display::fmt()`]. As a result, [`JoinOrOnce`] does not implement
+/// [`Sync`].
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::join_or_once([""; 0], " ", "(none)");
+/// assert_eq!(value.to_string(), "(none)");
+///
+/// assert_eq!(value.to_string(), "");
+/// ```
+pub fn join_or_once<I, S, E>(
+    iter: I,
+    sep: S,
+    empty: E,
+) -> JoinOrOnce<I::IntoIter, S, E>
 where
-    I: Iterator,
-    I::Item: Debug,
-    S: Display,
+    I: IntoIterator,
 {
-    fn fmt(&self, f: &mut Formatter) -> Result {
-        if let Some(mut iter) = self.iter.take() {
-            if let Some(item) = iter.next() {
-                write!(f, "{:?}", item)?;
-            }
-
-            for item in iter {
-                write!(f, "{}{:?}", self.sep, item)?;
-            }
-        }
-        Ok(())
-    }
+    JoinOr { iter: Once::new(iter.into_iter()), sep, empty }
 }
 
-impl<I, S> Display for JoinOnce<I, S>
+/// Concatenates mapped [`Iterator`] results with a separator between each, or
+/// writes `empty` if there are no items.
+///
+/// If [`Clone`] for the [`Iterator`] is too expensive, consider using
+/// [`join_map_or_once()`].
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::join_map_or(["hola", "mundo"], " ", "-", fmty::to_ascii_uppercase);
+/// assert_eq!(value.to_string(), "HOLA MUNDO");
+///
+/// let value = fmty::join_map_or([""; 0], " ", "-", fmty::to_ascii_uppercase);
+/// assert_eq!(value.to_string(), "-");
+/// ```
+pub fn join_map_or<I, S, E, R, F>(
+    iter: I,
+    sep: S,
+    empty: E,
+    f: F,
+) -> JoinMapOr<I::IntoIter, S, E, F>
 where
-    I: Iterator,
-    I::Item: Display,
-    S: Display,
+    I: IntoIterator,
+    I::IntoIter: Clone,
+    F: Fn(I::Item) -> R,
 {
-    fn fmt(&self, f: &mut Formatter) -> Result {
-        if let Some(mut iter) = self.iter.take() {
-            if let Some(item) = iter.next() {
-                write!(f, "{}", item)?;
-            }
-
-            for item in iter {
-                write!(f, "{}{}", self.sep, item)?;
-            }
-        }
-        Ok(())
-    }
+    JoinMapOr { iter: iter.into_iter(), sep, empty, map: f }
 }
 
-impl<I, S, F, R> Debug for JoinMap<I, S, F>
+/// Concatenates mapped [`Iterator`] results with a separator between each, or
+/// writes `empty` if there are no items, at most once.
+///
+/// This is a non-[`Clone`] alternative to [`join_map_or()`]. It uses interior
+/// mutability to take ownership of the iterator in the first call to
+/// [`Display::fmt()`]. As a result, [`JoinMapOrOnce`] does not implement
+/// [`Sync`].
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::join_map_or_once([""; 0], " ", "-", fmty::to_ascii_uppercase);
+/// assert_eq!(value.to_string(), "-");
+///
+/// assert_eq!(value.to_string(), "");
+/// ```
+pub fn join_map_or_once<I, S, E, R, F>(
+    iter: I,
+    sep: S,
+    empty: E,
+    f: F,
+) -> JoinMapOrOnce<I::IntoIter, S, E, F>
 where
-    I: Iterator + Clone,
-    S: Display,
+    I: IntoIterator,
     F: Fn(I::Item) -> R,
-    R: Debug,
 {
-    fn fmt(&self, f: &mut Formatter) -> Result {
-        let mut iter = self.iter.clone();
-
-        if let Some(item) = iter.next() {
-            write!(f, "{:?}", (self.map)(item))?;
-        }
-
-        for item in iter {
-            write!(f, "{}{:?}", self.sep, (self.map)(item))?;
-        }
-
-        Ok(())
-    }
+    JoinMapOr { iter: Once::new(iter.into_iter()), sep, empty, map: f }
+}
+
+/// Concatenates [`Iterator`] items with a separator between each, up to
+/// `max_bytes` of output.
+///
+/// If the next item and its separator would exceed `max_bytes`, `overflow` is
+/// written in place of them and the remaining items. The bytes of `overflow`
+/// are not counted toward the budget, so the output before it never exceeds
+/// `max_bytes`.
+///
+/// Because each item must be measured before it is written, each item is
+/// formatted twice.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::join_budget(["alpha", "beta", "gamma"], ", ", 12, ", …");
+/// assert_eq!(value.to_string(), "alpha, beta, …");
+/// ```
+pub fn join_budget<I, S, O>(
+    iter: I,
+    sep: S,
+    max_bytes: usize,
+    overflow: O,
+) -> JoinBudget<I::IntoIter, S, O>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+{
+    JoinBudget { iter: iter.into_iter(), sep, max_bytes, overflow }
+}
+
+/// Concatenates [`Iterator`] items, each prefixed by a label and a space, with
+/// a separator between each.
+///
+/// Labels and items are paired like with [`Iterator::zip()`], so writing stops
+/// at the end of the shorter [`Iterator`].
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::join_prefixed(["a)", "b)"], ["red", "blue"], ", ");
+/// assert_eq!(value.to_string(), "a) red, b) blue");
+/// ```
+pub fn join_prefixed<L, I, S>(
+    labels: L,
+    iter: I,
+    sep: S,
+) -> JoinPrefixed<L::IntoIter, I::IntoIter, S>
+where
+    L: IntoIterator,
+    L::IntoIter: Clone,
+    I: IntoIterator,
+    I::IntoIter: Clone,
+{
+    JoinPrefixed { labels: labels.into_iter(), iter: iter.into_iter(), sep }
+}
+
+/// Concatenates [`Iterator`] items with a separator between each, or a group
+/// separator between items with different keys.
+///
+/// Consecutive items with equal keys form a group, like with
+/// [`slice::chunk_by()`](https://doc.rust-lang.org/std/primitive.slice.html#method.chunk_by).
+/// Items are not sorted, so equal keys that are not adjacent form separate
+/// groups.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::group_runs(
+///     ["a1", "a2", "b1", "c1", "c2"],
+///     " ",
+///     |item| item.as_bytes()[0],
+///     " | ",
+/// );
+/// assert_eq!(value.to_string(), "a1 a2 | b1 | c1 c2");
+/// ```
+pub fn group_runs<I, S, K, F, G>(
+    iter: I,
+    sep: S,
+    key: F,
+    group_sep: G,
+) -> GroupRuns<I::IntoIter, S, F, G>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+    K: PartialEq,
+    F: Fn(&I::Item) -> K,
+{
+    GroupRuns { iter: iter.into_iter(), sep, key, group_sep }
+}
+
+/// Concatenates [`Iterator`] items in [natural sort order](https://en.wikipedia.org/wiki/Natural_sort_order),
+/// with a separator between each.
+///
+/// Items are compared by their output, split into runs of ASCII digits and
+/// runs of other characters. Digit runs are compared by numeric value, so
+/// `file2` comes before `file10`. Other runs are compared like [`str`].
+///
+/// Each item is written to a [`String`] which are then sorted, so this
+/// allocates once per item plus once for the list. This requires the `alloc`
+/// feature.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::join_natural_sorted(["file10", "file2", "file1"], ", ");
+/// assert_eq!(value.to_string(), "file1, file2, file10");
+/// ```
+///
+/// [`String`]: alloc::string::String
+#[cfg(feature = "alloc")]
+pub fn join_natural_sorted<I, S>(
+    iter: I,
+    sep: S,
+) -> JoinNaturalSorted<I::IntoIter, S>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+{
+    JoinNaturalSorted { iter: iter.into_iter(), sep }
+}
+
+/// Concatenates up to `max` [`Iterator`] items with a separator between each,
+/// followed by `more(remaining)` if any items are not written.
+///
+/// The number of remaining items comes from [`ExactSizeIterator::len()`], so
+/// items past `max` are never iterated.
+///
+/// # Examples
+///
+/// ```
+/// let more = |n| fmty::concat_tuple((" … and ", n, " more"));
+///
+/// let value = fmty::join_preview(["a", "b", "c", "d"], ", ", 2, more);
+/// assert_eq!(value.to_string(), "a, b … and 2 more");
+///
+/// let value = fmty::join_preview(["a", "b"], ", ", 2, more);
+/// assert_eq!(value.to_string(), "a, b");
+/// ```
+pub fn join_preview<I, S, R, F>(
+    iter: I,
+    sep: S,
+    max: usize,
+    more: F,
+) -> JoinPreview<I::IntoIter, S, F>
+where
+    I: IntoIterator,
+    I::IntoIter: ExactSizeIterator + Clone,
+    F: Fn(usize) -> R,
+{
+    JoinPreview { iter: iter.into_iter(), sep, max, more }
+}
+
+/// Concatenates [`Iterator`] items quoted by `quote` with a separator between
+/// each.
+///
+/// `quote` is meant to be one of the `quote_*` functions, such as
+/// [`quote_double()`](crate::quote_double()). This is equivalent to
+/// <code>[join_map]\(iter, sep, quote\)</code>.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::join_quoted(["a", "b"], ", ", fmty::quote_double);
+/// assert_eq!(value.to_string(), "\"a\", \"b\"");
+/// ```
+pub fn join_quoted<I, S, R, F>(
+    iter: I,
+    sep: S,
+    quote: F,
+) -> JoinQuoted<I::IntoIter, S, F>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+    F: Fn(I::Item) -> R,
+{
+    join_map(iter, sep, quote)
+}
+
+/// Concatenates [`Iterator`] items with a separator between each, skipping
+/// items that write nothing.
+///
+/// Unlike [`join()`], this never writes separators next to each other or at
+/// either end because of empty items.
+///
+/// Because each item must be checked for output before its separator is
+/// written, each non-empty item is formatted twice. Checking stops at the
+/// item's first write.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::join_compact(["", "a", "", "c", ""], ",");
+/// assert_eq!(value.to_string(), "a,c");
+/// ```
+pub fn join_compact<I, S>(iter: I, sep: S) -> JoinCompact<I::IntoIter, S>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+{
+    JoinCompact { iter: iter.into_iter(), sep }
+}
+
+/// Concatenates [`Iterator`] items with a separator between each, or
+/// `big_sep` after every `every` items.
+///
+/// This is useful for breaking long lists into lines, or for joining items in
+/// chunks of `every`. If `every` is 0, only `sep` is written.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::join_every(1..=7, ", ", 3, ",\n");
+/// assert_eq!(value.to_string(), "1, 2, 3,\n4, 5, 6,\n7");
+/// ```
+#[doc(alias = "join_chunks")]
+pub fn join_every<I, S, B>(
+    iter: I,
+    sep: S,
+    every: usize,
+    big_sep: B,
+) -> JoinEvery<I::IntoIter, S, B>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+{
+    JoinEvery { iter: iter.into_iter(), sep, every, big_sep }
+}
+
+/// Concatenates values at their indices with a separator between each,
+/// writing `gap_fill` at indices without a value.
+///
+/// Pairs are expected to be sorted by index in ascending order, starting from
+/// index 0. Nothing is written after the last value. Values whose index is not
+/// after the previous value's index are written without gaps.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::fill_sparse([(0, "a"), (2, "c")], "-", ", ");
+/// assert_eq!(value.to_string(), "a, -, c");
+/// ```
+pub fn fill_sparse<I, T, G, S>(
+    pairs: I,
+    gap_fill: G,
+    sep: S,
+) -> FillSparse<I::IntoIter, G, S>
+where
+    I: IntoIterator<Item = (usize, T)>,
+    I::IntoIter: Clone,
+{
+    FillSparse { iter: pairs.into_iter(), gap_fill, sep }
+}
+
+/// Concatenates [`Iterator`] items in reverse order with a separator between
+/// each.
+///
+/// Items are collected into a [`Vec`] each time this is formatted. If the
+/// [`Iterator`] is a [`DoubleEndedIterator`], prefer
+/// <code>[join]\(iter.rev\(\), sep\)</code>, which does not allocate. This
+/// requires the `alloc` feature.
+///
+/// # Examples
+///
+/// ```
+/// let mut n = 0;
+/// let iter = std::iter::from_fn(move || {
+///     n += 1;
+///     if n <= 3 { Some(n) } else { None }
+/// });
+///
+/// let value = fmty::join_rev_collect(iter, ", ");
+/// assert_eq!(value.to_string(), "3, 2, 1");
+/// ```
+///
+/// [`Vec`]: alloc::vec::Vec
+#[cfg(feature = "alloc")]
+pub fn join_rev_collect<I, S>(iter: I, sep: S) -> JoinRevCollect<I::IntoIter, S>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+{
+    JoinRevCollect { iter: iter.into_iter(), sep }
+}
+
+/// Concatenates numbers with a separator between each, collapsing runs of
+/// consecutive numbers into `start-end`.
+///
+/// Numbers are expected to be sorted in ascending order. Numbers that are not
+/// one more than the previous start a new run.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::join_ranges([1, 2, 3, 5, 7, 8], ", ");
+/// assert_eq!(value.to_string(), "1-3, 5, 7-8");
+/// ```
+pub fn join_ranges<I, S>(iter: I, sep: S) -> JoinRanges<I::IntoIter, S>
+where
+    I: IntoIterator<Item = u64>,
+    I::IntoIter: Clone,
+{
+    JoinRanges { iter: iter.into_iter(), sep }
+}
+
+/// Concatenates the [`Ok`] values of [`Result`]s with a separator between
+/// each, followed by a summary of the [`Err`] values.
+///
+/// If there are errors, `err_summary` is called with the number of errors and
+/// the first error, and its result is written after the [`Ok`] values. Items
+/// are iterated once per format.
+///
+/// # Examples
+///
+/// ```
+/// let results = [Ok(1), Err("bad"), Ok(2), Err("worse")];
+///
+/// let value = fmty::join_results(results, ", ", |n, first| {
+///     fmty::concat_tuple((" (", n, " failed, first: ", *first, ")"))
+/// });
+///
+/// assert_eq!(value.to_string(), "1, 2 (2 failed, first: bad)");
+/// ```
+pub fn join_results<I, T, E, S, R, F>(
+    iter: I,
+    ok_sep: S,
+    err_summary: F,
+) -> JoinResults<I::IntoIter, S, F>
+where
+    I: IntoIterator<Item = core::result::Result<T, E>>,
+    I::IntoIter: Clone,
+    F: Fn(usize, &E) -> R,
+{
+    JoinResults { iter: iter.into_iter(), ok_sep, err_summary }
+}
+
+/// Concatenates [`Iterator`] items with a separator between each, skipping
+/// items that write nothing or fail to format.
+///
+/// Like [`join_compact()`], this never writes separators next to each other or
+/// at either end. Unlike it, an item whose [`Display`] implementation returns
+/// an error is skipped rather than stopping the whole write, so partial output
+/// from a failed item never appears.
+///
+/// Because each item must be fully checked before its separator is written,
+/// each written item is formatted twice.
+///
+/// # Examples
+///
+/// ```
+/// use std::fmt;
+///
+/// struct Port(u32);
+///
+/// impl fmt::Display for Port {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         if self.0 > u16::MAX as u32 {
+///             return Err(fmt::Error);
+///         }
+///         write!(f, ":{}", self.0)
+///     }
+/// }
+///
+/// let ports = [Port(80), Port(70000), Port(443)];
+///
+/// let value = fmty::join_strict(&ports, ", ");
+/// assert_eq!(value.to_string(), ":80, :443");
+/// ```
+pub fn join_strict<I, S>(iter: I, sep: S) -> JoinStrict<I::IntoIter, S>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+{
+    JoinStrict { iter: iter.into_iter(), sep }
+}
+
+/// Concatenates [`Iterator`] items with a separator between each, skipping
+/// empty items at either end.
+///
+/// Unlike [`join()`], this never writes a separator at the start or end
+/// because the first or last items are empty. Unlike [`join_compact()`], empty
+/// items between non-empty ones are kept, so their separators still appear.
+///
+/// Because the non-empty range must be found before writing, each item is
+/// formatted up to twice. Checking stops at the item's first write.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::join_skip_empty_edges(["", "a", "", "c", ""], ",");
+/// assert_eq!(value.to_string(), "a,,c");
+/// ```
+pub fn join_skip_empty_edges<I, S>(
+    iter: I,
+    sep: S,
+) -> JoinSkipEmptyEdges<I::IntoIter, S>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+{
+    JoinSkipEmptyEdges { iter: iter.into_iter(), sep }
+}
+
+/// Concatenates [`Iterator`] items as an English list with an Oxford comma.
+///
+/// Two items are separated by ` and `. Three or more items are separated by
+/// `, `, with `, and ` before the last. This is equivalent to
+/// <code>[conjunction_list]\(iter, \"and\", true\)</code>.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::list_english(["a", "b"]);
+/// assert_eq!(value.to_string(), "a and b");
+///
+/// let value = fmty::list_english(["a", "b", "c"]);
+/// assert_eq!(value.to_string(), "a, b, and c");
+/// ```
+pub fn list_english<I>(iter: I) -> ListEnglish<I::IntoIter>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+{
+    conjunction_list(iter, "and", true)
+}
+
+/// Concatenates [`Iterator`] items as a list joined by the conjunction `conj`,
+/// like `and` or `or`.
+///
+/// Two items are separated by ` conj ` without a comma. Three or more items
+/// are separated by `, `, with `, conj ` before the last if `oxford` is
+/// `true`, or ` conj ` otherwise.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::conjunction_list(["red", "green", "blue"], "or", true);
+/// assert_eq!(value.to_string(), "red, green, or blue");
+///
+/// let value = fmty::conjunction_list(["red", "green", "blue"], "or", false);
+/// assert_eq!(value.to_string(), "red, green or blue");
+/// ```
+pub fn conjunction_list<I, C>(
+    iter: I,
+    conj: C,
+    oxford: bool,
+) -> ConjunctionList<I::IntoIter, C>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+{
+    ConjunctionList { iter: iter.into_iter(), conj, oxford }
+}
+
+/// Concatenates [`Iterator`] items with a separator between each, shortening
+/// each item to `item_max` [`char`]s ending with `…`.
+///
+/// Items are shortened like with [`compact_unless_alternate()`], so the
+/// ellipsis counts toward `item_max` and is only written for long items.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::join_map_truncate(["short", "much longer"], " | ", 6);
+/// assert_eq!(value.to_string(), "short | much …");
+/// ```
+pub fn join_map_truncate<I, S>(
+    iter: I,
+    sep: S,
+    item_max: usize,
+) -> JoinMapTruncate<I::IntoIter, S>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+{
+    JoinMapTruncate { iter: iter.into_iter(), sep, item_max }
+}
+
+/// Concatenates [`Iterator`] items with separators alternating between `sep_a`
+/// and `sep_b`, starting with `sep_a`.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::join_alt_sep(["a", "b", "c", "d"], "-", "+");
+/// assert_eq!(value.to_string(), "a-b+c-d");
+/// ```
+pub fn join_alt_sep<I, A, B>(
+    iter: I,
+    sep_a: A,
+    sep_b: B,
+) -> JoinAltSep<I::IntoIter, A, B>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+{
+    JoinAltSep { iter: iter.into_iter(), sep_a, sep_b }
+}
+
+/// Concatenates [`Iterator`] items as a breadcrumb trail with ` › ` between
+/// each.
+///
+/// This is equivalent to <code>[join]\(iter, \" › \"\)</code>.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::breadcrumb(["Home", "Docs", "fmty"]);
+/// assert_eq!(value.to_string(), "Home › Docs › fmty");
+/// ```
+pub fn breadcrumb<I>(iter: I) -> Breadcrumb<I::IntoIter>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+{
+    breadcrumb_sep(iter, " › ")
+}
+
+/// Concatenates [`Iterator`] items as a breadcrumb trail with a custom
+/// separator.
+///
+/// This is equivalent to <code>[join]\(iter, sep\)</code>.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::breadcrumb_sep(["Home", "Docs", "fmty"], " / ");
+/// assert_eq!(value.to_string(), "Home / Docs / fmty");
+/// ```
+pub fn breadcrumb_sep<I, S>(iter: I, sep: S) -> Breadcrumb<I::IntoIter, S>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+{
+    join(iter, sep)
+}
+
+/// Concatenates [`Iterator`] items with a separator between each, omitting
+/// items for which `skip` returns `true`.
+///
+/// Skipped items write no separator, so separators never appear next to each
+/// other or at either end. Unlike [`join_compact()`], items are not formatted
+/// to decide whether to skip them.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::join_skip(["a", "-", "b", "-"], ", ", |&s| s == "-");
+/// assert_eq!(value.to_string(), "a, b");
+/// ```
+pub fn join_skip<I, S, F>(
+    iter: I,
+    sep: S,
+    skip: F,
+) -> JoinSkip<I::IntoIter, S, F>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+    F: Fn(&I::Item) -> bool,
+{
+    JoinSkip { iter: iter.into_iter(), sep, skip }
+}
+
+/// Concatenates the first `head` and last `tail` [`Iterator`] items with a
+/// separator between each, and `ellipsis` in place of the items between them.
+///
+/// If there are no more than `head + tail` items, every item is written and
+/// `ellipsis` is not. The number of items comes from
+/// [`ExactSizeIterator::len()`], so skipped items are never formatted.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::join_head_tail(1..11, ", ", 2, 2, "…");
+/// assert_eq!(value.to_string(), "1, 2, …, 9, 10");
+///
+/// let value = fmty::join_head_tail(1..5, ", ", 2, 2, "…");
+/// assert_eq!(value.to_string(), "1, 2, 3, 4");
+/// ```
+pub fn join_head_tail<I, S, E>(
+    iter: I,
+    sep: S,
+    head: usize,
+    tail: usize,
+    ellipsis: E,
+) -> JoinHeadTail<I::IntoIter, S, E>
+where
+    I: IntoIterator,
+    I::IntoIter: ExactSizeIterator + Clone,
+{
+    JoinHeadTail { iter: iter.into_iter(), sep, head, tail, ellipsis }
+}
+
+/// Concatenates [`Iterator`] items in pages of `per_page` items, with
+/// `item_sep` between items and `page_sep(page)` between pages.
+///
+/// Pages are numbered from 1, and `page_sep` is given the number of the page
+/// that follows it. If `per_page` is 0, all items are on one page.
+///
+/// This is like [`join_every()`], but with a separator that depends on the
+/// page.
+///
+/// # Examples
+///
+/// ```
+/// let page_sep = |n| fmty::concat_tuple(("\n--- page ", n, " ---\n"));
+///
+/// let value = fmty::join_paged(1..=5, 3, ", ", page_sep);
+/// assert_eq!(value.to_string(), "1, 2, 3\n--- page 2 ---\n4, 5");
+/// ```
+pub fn join_paged<I, S, R, F>(
+    iter: I,
+    per_page: usize,
+    item_sep: S,
+    page_sep: F,
+) -> JoinPaged<I::IntoIter, S, F>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+    F: Fn(usize) -> R,
+{
+    JoinPaged { iter: iter.into_iter(), per_page, item_sep, page_sep }
+}
+
+impl<I, S> Debug for Join<I, S>
+where
+    I: Iterator + Clone,
+    I::Item: Debug,
+    S: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let mut iter = self.iter.clone();
+
+        if let Some(item) = iter.next() {
+            write!(f, "{:?}", item)?;
+        }
+
+        for item in iter {
+            write!(f, "{}{:?}", self.sep, item)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<I, S> Display for Join<I, S>
+where
+    I: Iterator + Clone,
+    I::Item: Display,
+    S: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let mut iter = self.iter.clone();
+
+        if let Some(item) = iter.next() {
+            write!(f, "{}", item)?;
+        }
+
+        for item in iter {
+            write!(f, "{}{}", self.sep, item)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<I, S> Debug for JoinOnce<I, S>
+where
+    I: Iterator,
+    I::Item: Debug,
+    S: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        if let Some(mut iter) = self.iter.take() {
+            if let Some(item) = iter.next() {
+                write!(f, "{:?}", item)?;
+            }
+
+            for item in iter {
+                write!(f, "{}{:?}", self.sep, item)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<I, S> Display for JoinOnce<I, S>
+where
+    I: Iterator,
+    I::Item: Display,
+    S: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        if let Some(mut iter) = self.iter.take() {
+            if let Some(item) = iter.next() {
+                write!(f, "{}", item)?;
+            }
+
+            for item in iter {
+                write!(f, "{}{}", self.sep, item)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<I, S, F, R> Debug for JoinMap<I, S, F>
+where
+    I: Iterator + Clone,
+    S: Display,
+    F: Fn(I::Item) -> R,
+    R: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let mut iter = self.iter.clone();
+
+        if let Some(item) = iter.next() {
+            write!(f, "{:?}", (self.map)(item))?;
+        }
+
+        for item in iter {
+            write!(f, "{}{:?}", self.sep, (self.map)(item))?;
+        }
+
+        Ok(())
+    }
 }
 
 impl<I, S, F, R> Display for JoinMap<I, S, F>
 where
     I: Iterator + Clone,
     S: Display,
-    F: Fn(I::Item) -> R,
-    R: Display,
+    F: Fn(I::Item) -> R,
+    R: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let mut iter = self.iter.clone();
+
+        if let Some(item) = iter.next() {
+            write!(f, "{}", (self.map)(item))?;
+        }
+
+        for item in iter {
+            write!(f, "{}{}", self.sep, (self.map)(item))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<I, S, F, R> Debug for JoinMapOnce<I, S, F>
+where
+    I: Iterator,
+    S: Display,
+    F: Fn(I::Item) -> R,
+    R: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        if let Some(mut iter) = self.iter.take() {
+            if let Some(item) = iter.next() {
+                write!(f, "{:?}", (self.map)(item))?;
+            }
+
+            for item in iter {
+                write!(f, "{}{:?}", self.sep, (self.map)(item))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<I, S, F, R> Display for JoinMapOnce<I, S, F>
+where
+    I: Iterator,
+    S: Display,
+    F: Fn(I::Item) -> R,
+    R: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        if let Some(mut iter) = self.iter.take() {
+            if let Some(item) = iter.next() {
+                write!(f, "{}", (self.map)(item))?;
+            }
+
+            for item in iter {
+                write!(f, "{}{}", self.sep, (self.map)(item))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<I> Display for JoinNames<I>
+where
+    I: ExactSizeIterator + Clone,
+    I::Item: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let iter = self.iter.clone();
+        let len = iter.len();
+        let shown = len.min(self.max);
+
+        for (i, item) in iter.take(shown).enumerate() {
+            if i == 0 {
+                write!(f, "{}", item)?;
+            } else if i + 1 == len {
+                write!(f, " and {}", item)?;
+            } else {
+                write!(f, ", {}", item)?;
+            }
+        }
+
+        match len - shown {
+            0 => Ok(()),
+            rem => {
+                if shown != 0 {
+                    f.write_str(" and ")?;
+                }
+                let s = if rem == 1 { "" } else { "s" };
+                write!(f, "{} other{}", rem, s)
+            }
+        }
+    }
+}
+
+impl<I, S, E> Debug for JoinOr<I, S, E>
+where
+    I: Iterator + Clone,
+    I::Item: Debug,
+    S: Display,
+    E: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        if self.iter.clone().next().is_none() {
+            return write!(f, "{}", self.empty);
+        }
+        write!(f, "{:?}", join(self.iter.clone(), &self.sep))
+    }
+}
+
+impl<I, S, E> Display for JoinOr<I, S, E>
+where
+    I: Iterator + Clone,
+    I::Item: Display,
+    S: Display,
+    E: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        if self.iter.clone().next().is_none() {
+            return write!(f, "{}", self.empty);
+        }
+        write!(f, "{}", join(self.iter.clone(), &self.sep))
+    }
+}
+
+impl<I, S, E> Debug for JoinOrOnce<I, S, E>
+where
+    I: Iterator,
+    I::Item: Debug,
+    S: Display,
+    E: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        if let Some(iter) = self.iter.take() {
+            let mut iter = iter.peekable();
+
+            if iter.peek().is_none() {
+                return write!(f, "{}", self.empty);
+            }
+            write!(f, "{:?}", join_once(iter, &self.sep))?;
+        }
+        Ok(())
+    }
+}
+
+impl<I, S, E> Display for JoinOrOnce<I, S, E>
+where
+    I: Iterator,
+    I::Item: Display,
+    S: Display,
+    E: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        if let Some(iter) = self.iter.take() {
+            let mut iter = iter.peekable();
+
+            if iter.peek().is_none() {
+                return write!(f, "{}", self.empty);
+            }
+            write!(f, "{}", join_once(iter, &self.sep))?;
+        }
+        Ok(())
+    }
+}
+
+impl<I, S, E, F, R> Debug for JoinMapOr<I, S, E, F>
+where
+    I: Iterator + Clone,
+    S: Display,
+    E: Display,
+    F: Fn(I::Item) -> R,
+    R: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        if self.iter.clone().next().is_none() {
+            return write!(f, "{}", self.empty);
+        }
+        write!(f, "{:?}", join_map(self.iter.clone(), &self.sep, &self.map))
+    }
+}
+
+impl<I, S, E, F, R> Display for JoinMapOr<I, S, E, F>
+where
+    I: Iterator + Clone,
+    S: Display,
+    E: Display,
+    F: Fn(I::Item) -> R,
+    R: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        if self.iter.clone().next().is_none() {
+            return write!(f, "{}", self.empty);
+        }
+        write!(f, "{}", join_map(self.iter.clone(), &self.sep, &self.map))
+    }
+}
+
+impl<I, S, E, F, R> Debug for JoinMapOrOnce<I, S, E, F>
+where
+    I: Iterator,
+    S: Display,
+    E: Display,
+    F: Fn(I::Item) -> R,
+    R: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        if let Some(iter) = self.iter.take() {
+            let mut iter = iter.peekable();
+
+            if iter.peek().is_none() {
+                return write!(f, "{}", self.empty);
+            }
+            write!(f, "{:?}", join_map_once(iter, &self.sep, &self.map))?;
+        }
+        Ok(())
+    }
+}
+
+impl<I, S, E, F, R> Display for JoinMapOrOnce<I, S, E, F>
+where
+    I: Iterator,
+    S: Display,
+    E: Display,
+    F: Fn(I::Item) -> R,
+    R: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        if let Some(iter) = self.iter.take() {
+            let mut iter = iter.peekable();
+
+            if iter.peek().is_none() {
+                return write!(f, "{}", self.empty);
+            }
+            write!(f, "{}", join_map_once(iter, &self.sep, &self.map))?;
+        }
+        Ok(())
+    }
+}
+
+impl<I, S, O> Display for JoinBudget<I, S, O>
+where
+    I: Iterator + Clone,
+    I::Item: Display,
+    S: Display,
+    O: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let sep_len = byte_len(&self.sep)?;
+        let mut len = 0;
+
+        for (i, item) in self.iter.clone().enumerate() {
+            let sep_len = if i == 0 { 0 } else { sep_len };
+            let new_len = len + sep_len + byte_len(&item)?;
+
+            if new_len > self.max_bytes {
+                return write!(f, "{}", self.overflow);
+            }
+
+            if i != 0 {
+                write!(f, "{}", self.sep)?;
+            }
+            write!(f, "{}", item)?;
+            len = new_len;
+        }
+
+        Ok(())
+    }
+}
+
+impl<L, I, S> Debug for JoinPrefixed<L, I, S>
+where
+    L: Iterator + Clone,
+    L::Item: Display,
+    I: Iterator + Clone,
+    I::Item: Debug,
+    S: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let pairs = self.labels.clone().zip(self.iter.clone());
+
+        for (i, (label, item)) in pairs.enumerate() {
+            if i != 0 {
+                write!(f, "{}", self.sep)?;
+            }
+            write!(f, "{} {:?}", label, item)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<L, I, S> Display for JoinPrefixed<L, I, S>
+where
+    L: Iterator + Clone,
+    L::Item: Display,
+    I: Iterator + Clone,
+    I::Item: Display,
+    S: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let pairs = self.labels.clone().zip(self.iter.clone());
+
+        for (i, (label, item)) in pairs.enumerate() {
+            if i != 0 {
+                write!(f, "{}", self.sep)?;
+            }
+            write!(f, "{} {}", label, item)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<I, S, K, F, G> Display for GroupRuns<I, S, F, G>
+where
+    I: Iterator + Clone,
+    I::Item: Display,
+    S: Display,
+    K: PartialEq,
+    F: Fn(&I::Item) -> K,
+    G: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let mut prev_key = None;
+
+        for item in self.iter.clone() {
+            let key = (self.key)(&item);
+
+            match prev_key {
+                Some(prev_key) if prev_key == key => write!(f, "{}", self.sep)?,
+                Some(_) => write!(f, "{}", self.group_sep)?,
+                None => {}
+            }
+
+            write!(f, "{}", item)?;
+            prev_key = Some(key);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I, S> Display for JoinNaturalSorted<I, S>
+where
+    I: Iterator + Clone,
+    I::Item: Display,
+    S: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        use alloc::{string::String, vec::Vec};
+
+        let mut items = Vec::new();
+        for item in self.iter.clone() {
+            let mut buf = String::new();
+            write!(buf, "{}", item)?;
+            items.push(buf);
+        }
+
+        items.sort_by(|a, b| natural_cmp(a, b));
+
+        for (i, item) in items.iter().enumerate() {
+            if i != 0 {
+                write!(f, "{}", self.sep)?;
+            }
+            f.write_str(item)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Compares strings by runs of digits and non-digits, with digit runs compared
+/// numerically.
+#[cfg(feature = "alloc")]
+fn natural_cmp(mut a: &str, mut b: &str) -> core::cmp::Ordering {
+    use core::cmp::Ordering;
+
+    /// Splits off the leading run of digits or non-digits.
+    fn split_run(s: &str) -> (&str, &str) {
+        let is_digit = s.as_bytes()[0].is_ascii_digit();
+        let end = s
+            .bytes()
+            .position(|b| b.is_ascii_digit() != is_digit)
+            .unwrap_or(s.len());
+        s.split_at(end)
+    }
+
+    loop {
+        match (a.is_empty(), b.is_empty()) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return Ordering::Less,
+            (false, true) => return Ordering::Greater,
+            (false, false) => {}
+        }
+
+        let (a_run, a_rest) = split_run(a);
+        let (b_run, b_rest) = split_run(b);
+
+        let is_digits = |run: &str| run.as_bytes()[0].is_ascii_digit();
+
+        let ordering = if is_digits(a_run) && is_digits(b_run) {
+            let a_num = a_run.trim_start_matches('0');
+            let b_num = b_run.trim_start_matches('0');
+
+            // Fewer leading zeros comes first among equal numbers.
+            a_num
+                .len()
+                .cmp(&b_num.len())
+                .then_with(|| a_num.cmp(b_num))
+                .then_with(|| a_run.len().cmp(&b_run.len()))
+        } else {
+            a_run.cmp(b_run)
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+
+        a = a_rest;
+        b = b_rest;
+    }
+}
+
+impl<I, S, R, F> Display for JoinPreview<I, S, F>
+where
+    I: ExactSizeIterator + Clone,
+    I::Item: Display,
+    S: Display,
+    R: Display,
+    F: Fn(usize) -> R,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let iter = self.iter.clone();
+        let len = iter.len();
+
+        for (i, item) in iter.take(self.max).enumerate() {
+            if i != 0 {
+                write!(f, "{}", self.sep)?;
+            }
+            write!(f, "{}", item)?;
+        }
+
+        match len.saturating_sub(self.max) {
+            0 => Ok(()),
+            rem => write!(f, "{}", (self.more)(rem)),
+        }
+    }
+}
+
+impl<I, S> Display for JoinCompact<I, S>
+where
+    I: Iterator + Clone,
+    I::Item: Display,
+    S: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let mut is_first = true;
+
+        for item in self.iter.clone() {
+            if is_empty(&item)? {
+                continue;
+            }
+
+            if !is_first {
+                write!(f, "{}", self.sep)?;
+            }
+            write!(f, "{}", item)?;
+            is_first = false;
+        }
+
+        Ok(())
+    }
+}
+
+impl<I, S, B> Display for JoinEvery<I, S, B>
+where
+    I: Iterator + Clone,
+    I::Item: Display,
+    S: Display,
+    B: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        for (i, item) in self.iter.clone().enumerate() {
+            if i != 0 {
+                if self.every != 0 && i % self.every == 0 {
+                    write!(f, "{}", self.big_sep)?;
+                } else {
+                    write!(f, "{}", self.sep)?;
+                }
+            }
+            write!(f, "{}", item)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<I, T, G, S> Display for FillSparse<I, G, S>
+where
+    I: Iterator<Item = (usize, T)> + Clone,
+    T: Display,
+    G: Display,
+    S: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let mut next = 0;
+        let mut is_first = true;
+
+        for (index, value) in self.iter.clone() {
+            while next < index {
+                if !is_first {
+                    write!(f, "{}", self.sep)?;
+                }
+                write!(f, "{}", self.gap_fill)?;
+                is_first = false;
+                next += 1;
+            }
+
+            if !is_first {
+                write!(f, "{}", self.sep)?;
+            }
+            write!(f, "{}", value)?;
+            is_first = false;
+            next = next.max(index.saturating_add(1));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I, S> Debug for JoinRevCollect<I, S>
+where
+    I: Iterator + Clone,
+    I::Item: Debug,
+    S: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let items: alloc::vec::Vec<_> = self.iter.clone().collect();
+
+        for (i, item) in items.iter().rev().enumerate() {
+            if i != 0 {
+                write!(f, "{}", self.sep)?;
+            }
+            write!(f, "{:?}", item)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I, S> Display for JoinRevCollect<I, S>
+where
+    I: Iterator + Clone,
+    I::Item: Display,
+    S: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let items: alloc::vec::Vec<_> = self.iter.clone().collect();
+
+        for (i, item) in items.iter().rev().enumerate() {
+            if i != 0 {
+                write!(f, "{}", self.sep)?;
+            }
+            write!(f, "{}", item)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<I, S> Display for JoinRanges<I, S>
+where
+    I: Iterator<Item = u64> + Clone,
+    S: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let mut iter = self.iter.clone().peekable();
+        let mut is_first = true;
+
+        while let Some(start) = iter.next() {
+            let mut end = start;
+            while let Some(&next) = iter.peek() {
+                if end.checked_add(1) != Some(next) {
+                    break;
+                }
+                end = next;
+                iter.next();
+            }
+
+            if !is_first {
+                write!(f, "{}", self.sep)?;
+            }
+            is_first = false;
+
+            if start == end {
+                write!(f, "{}", start)?;
+            } else {
+                write!(f, "{}-{}", start, end)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<I, T, E, S, R, F> Display for JoinResults<I, S, F>
+where
+    I: Iterator<Item = core::result::Result<T, E>> + Clone,
+    T: Display,
+    S: Display,
+    R: Display,
+    F: Fn(usize, &E) -> R,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let mut is_first = true;
+        let mut first_err = None;
+        let mut err_count = 0;
+
+        for result in self.iter.clone() {
+            match result {
+                Ok(value) => {
+                    if !is_first {
+                        write!(f, "{}", self.ok_sep)?;
+                    }
+                    write!(f, "{}", value)?;
+                    is_first = false;
+                }
+                Err(err) => {
+                    first_err.get_or_insert(err);
+                    err_count += 1;
+                }
+            }
+        }
+
+        match first_err {
+            Some(err) => write!(f, "{}", (self.err_summary)(err_count, &err)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<I, S> Display for JoinStrict<I, S>
+where
+    I: Iterator + Clone,
+    I::Item: Display,
+    S: Display,
 {
     fn fmt(&self, f: &mut Formatter) -> Result {
-        let mut iter = self.iter.clone();
+        let mut is_first = true;
 
-        if let Some(item) = iter.next() {
-            write!(f, "{}", (self.map)(item))?;
+        for item in self.iter.clone() {
+            match byte_len(&item) {
+                Ok(0) | Err(_) => continue,
+                Ok(_) => {}
+            }
+
+            if !is_first {
+                write!(f, "{}", self.sep)?;
+            }
+            write!(f, "{}", item)?;
+            is_first = false;
         }
 
-        for item in iter {
-            write!(f, "{}{}", self.sep, (self.map)(item))?;
+        Ok(())
+    }
+}
+
+impl<I, S> Display for JoinSkipEmptyEdges<I, S>
+where
+    I: Iterator + Clone,
+    I::Item: Display,
+    S: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let mut range = None;
+
+        for (i, item) in self.iter.clone().enumerate() {
+            if !is_empty(&item)? {
+                let start = range.map_or(i, |(start, _)| start);
+                range = Some((start, i));
+            }
+        }
+
+        let (start, end) = match range {
+            Some(range) => range,
+            None => return Ok(()),
+        };
+
+        let iter = self.iter.clone().skip(start).take(end + 1 - start);
+
+        for (i, item) in iter.enumerate() {
+            if i != 0 {
+                write!(f, "{}", self.sep)?;
+            }
+            write!(f, "{}", item)?;
         }
 
         Ok(())
     }
 }
 
-impl<I, S, F, R> Debug for JoinMapOnce<I, S, F>
+impl<I, C> Display for ConjunctionList<I, C>
 where
-    I: Iterator,
+    I: Iterator + Clone,
+    I::Item: Display,
+    C: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let mut iter = self.iter.clone().enumerate().peekable();
+
+        while let Some((i, item)) = iter.next() {
+            if i != 0 {
+                match (i, iter.peek()) {
+                    (_, Some(_)) => f.write_str(", ")?,
+                    (1, None) => write!(f, " {} ", self.conj)?,
+                    (_, None) if self.oxford => write!(f, ", {} ", self.conj)?,
+                    (_, None) => write!(f, " {} ", self.conj)?,
+                }
+            }
+            write!(f, "{}", item)?;
+        }
+        Ok(())
+    }
+}
+
+impl<I, S> Display for JoinMapTruncate<I, S>
+where
+    I: Iterator + Clone,
+    I::Item: Display,
     S: Display,
-    F: Fn(I::Item) -> R,
-    R: Debug,
 {
     fn fmt(&self, f: &mut Formatter) -> Result {
-        if let Some(mut iter) = self.iter.take() {
-            if let Some(item) = iter.next() {
-                write!(f, "{:?}", (self.map)(item))?;
+        for (i, item) in self.iter.clone().enumerate() {
+            if i != 0 {
+                write!(f, "{}", self.sep)?;
             }
+            write!(f, "{}", compact_unless_alternate(item, self.item_max))?;
+        }
+        Ok(())
+    }
+}
 
-            for item in iter {
-                write!(f, "{}{:?}", self.sep, (self.map)(item))?;
+impl<I, A, B> Display for JoinAltSep<I, A, B>
+where
+    I: Iterator + Clone,
+    I::Item: Display,
+    A: Display,
+    B: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        for (i, item) in self.iter.clone().enumerate() {
+            if i % 2 == 1 {
+                write!(f, "{}", self.sep_a)?;
+            } else if i != 0 {
+                write!(f, "{}", self.sep_b)?;
             }
+            write!(f, "{}", item)?;
         }
         Ok(())
     }
 }
 
-impl<I, S, F, R> Display for JoinMapOnce<I, S, F>
+impl<I, S, F> Display for JoinSkip<I, S, F>
 where
-    I: Iterator,
+    I: Iterator + Clone,
+    I::Item: Display,
     S: Display,
-    F: Fn(I::Item) -> R,
-    R: Display,
+    F: Fn(&I::Item) -> bool,
 {
     fn fmt(&self, f: &mut Formatter) -> Result {
-        if let Some(mut iter) = self.iter.take() {
-            if let Some(item) = iter.next() {
-                write!(f, "{}", (self.map)(item))?;
+        let items = self.iter.clone().filter(|item| !(self.skip)(item));
+
+        for (i, item) in items.enumerate() {
+            if i != 0 {
+                write!(f, "{}", self.sep)?;
             }
+            write!(f, "{}", item)?;
+        }
+        Ok(())
+    }
+}
 
-            for item in iter {
-                write!(f, "{}{}", self.sep, (self.map)(item))?;
+impl<I, S, E> Display for JoinHeadTail<I, S, E>
+where
+    I: ExactSizeIterator + Clone,
+    I::Item: Display,
+    S: Display,
+    E: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let iter = self.iter.clone();
+        let len = iter.len();
+
+        if len <= self.head.saturating_add(self.tail) {
+            return write!(f, "{}", join(iter, &self.sep));
+        }
+
+        for item in iter.clone().take(self.head) {
+            write!(f, "{}{}", item, self.sep)?;
+        }
+
+        write!(f, "{}", self.ellipsis)?;
+
+        for item in iter.skip(len - self.tail) {
+            write!(f, "{}{}", self.sep, item)?;
+        }
+        Ok(())
+    }
+}
+
+impl<I, S, R, F> Display for JoinPaged<I, S, F>
+where
+    I: Iterator + Clone,
+    I::Item: Display,
+    S: Display,
+    R: Display,
+    F: Fn(usize) -> R,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        for (i, item) in self.iter.clone().enumerate() {
+            if i != 0 {
+                if self.per_page != 0 && i % self.per_page == 0 {
+                    write!(f, "{}", (self.page_sep)(i / self.per_page + 1))?;
+                } else {
+                    write!(f, "{}", self.item_sep)?;
+                }
             }
+            write!(f, "{}", item)?;
         }
         Ok(())
     }
@@ -517,3 +2246,630 @@ macro_rules! impl_tuple_fmt_display {
 }
 
 impl_tuple!(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names() {
+        let names = ["Alice", "Bob", "Carol", "Dave", "Erin", "Frank"];
+
+        let cases: &[(usize, &str)] = &[
+            (0, ""),
+            (1, "Alice"),
+            (2, "Alice and Bob"),
+            (3, "Alice, Bob and 1 other"),
+            (6, "Alice, Bob and 4 others"),
+        ];
+
+        for &(len, expected) in cases {
+            assert_eq!(
+                join_names(&names[..len], 2).to_string(),
+                expected,
+                "incorrect result for length {len}",
+            );
+        }
+
+        assert_eq!(join_names(names, 0).to_string(), "6 others");
+        assert_eq!(
+            join_names(names, 9).to_string(),
+            "Alice, Bob, Carol, Dave, Erin and Frank"
+        );
+    }
+
+    #[test]
+    fn or() {
+        let empty: [&str; 0] = [];
+
+        assert_eq!(join_or(empty, ", ", "none").to_string(), "none");
+        assert_eq!(join_or(["a"], ", ", "none").to_string(), "a");
+        assert_eq!(join_or(["a", "b"], ", ", "none").to_string(), "a, b");
+        assert_eq!(format!("{:?}", join_or(empty, ", ", "none")), "none");
+        assert_eq!(
+            format!("{:?}", join_or(["a", "b"], ", ", "none")),
+            "\"a\", \"b\""
+        );
+
+        let value = join_or_once(["a", "b"], ", ", "none");
+        assert_eq!(value.to_string(), "a, b");
+        assert_eq!(value.to_string(), "");
+
+        let value = join_map_or(1..1, "+", "0", |x| x * 2);
+        assert_eq!(value.to_string(), "0");
+
+        let value = join_map_or(1..4, "+", "0", |x| x * 2);
+        assert_eq!(value.to_string(), "2+4+6");
+        assert_eq!(format!("{:?}", value), "2+4+6");
+
+        let value = join_map_or_once(1..1, "+", "0", |x| x * 2);
+        assert_eq!(value.to_string(), "0");
+        assert_eq!(value.to_string(), "");
+
+        let value = join_map_or_once(1..4, "+", "0", |x| x * 2);
+        assert_eq!(format!("{:?}", value), "2+4+6");
+        assert_eq!(format!("{:?}", value), "");
+
+        let value = join_or_once(empty, ", ", "none");
+        assert_eq!(format!("{:?}", value), "none");
+        assert_eq!(format!("{:?}", value), "");
+    }
+
+    #[test]
+    fn budget() {
+        let items = ["aaa", "bbb", "ccc", "ddd"];
+
+        // "aaa, bbb" is 8 bytes and "aaa, bbb, ccc" is 13 bytes.
+        for max in 8..13 {
+            assert_eq!(
+                join_budget(items, ", ", max, "...").to_string(),
+                "aaa, bbb...",
+                "incorrect result for budget {max}",
+            );
+        }
+
+        assert_eq!(
+            join_budget(items, ", ", 13, ", ...").to_string(),
+            "aaa, bbb, ccc, ..."
+        );
+        assert_eq!(
+            join_budget(items, ", ", 18, "...").to_string(),
+            "aaa, bbb, ccc, ddd"
+        );
+        assert_eq!(join_budget(items, ", ", 2, "...").to_string(), "...");
+        assert_eq!(join_budget([""; 0], ", ", 0, "...").to_string(), "");
+    }
+
+    #[test]
+    fn budget_bound() {
+        let items = ["alpha", "beta", "gamma", "δelta"];
+
+        for max in 0..30 {
+            let value = join_budget(items, ", ", max, "").to_string();
+            assert!(value.len() <= max, "{value:?} exceeds budget {max}");
+        }
+    }
+
+    #[test]
+    fn prefixed() {
+        let value = join_prefixed(["1.", "2.", "3."], ["a", "b", "c"], "\n");
+        assert_eq!(value.to_string(), "1. a\n2. b\n3. c");
+
+        let value = join_prefixed(["-"; 2], ["a", "b", "c"], " ");
+        assert_eq!(value.to_string(), "- a - b");
+
+        let value = join_prefixed(1.., ["a", "b"], ", ");
+        assert_eq!(value.to_string(), "1 a, 2 b");
+        assert_eq!(format!("{:?}", value), "1 \"a\", 2 \"b\"");
+
+        let value = join_prefixed(["x"], [""; 0], ", ");
+        assert_eq!(value.to_string(), "");
+    }
+
+    #[test]
+    fn runs() {
+        let first = |item: &&str| item.chars().next();
+
+        let value = group_runs(["a1", "a2", "b1", "c1", "c2"], ",", first, ";");
+        assert_eq!(value.to_string(), "a1,a2;b1;c1,c2");
+
+        let value = group_runs(["a1", "b1", "a2"], ",", first, ";");
+        assert_eq!(value.to_string(), "a1;b1;a2");
+
+        let value = group_runs(["x", "xy", "xyz"], ",", first, ";");
+        assert_eq!(value.to_string(), "x,xy,xyz");
+
+        let value = group_runs([""; 0], ",", first, ";");
+        assert_eq!(value.to_string(), "");
+
+        let value = group_runs(1..=6, " ", |n| n % 3 == 0, " / ");
+        assert_eq!(value.to_string(), "1 2 / 3 / 4 5 / 6");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn natural_sorted() {
+        let files =
+            ["img12.png", "img10.png", "IMG2.png", "img2.png", "img1.png"];
+        assert_eq!(
+            join_natural_sorted(files, " ").to_string(),
+            "IMG2.png img1.png img2.png img10.png img12.png",
+        );
+
+        let values = ["a01", "a1", "a001", "a0", "a", "1a", "10", "9"];
+        assert_eq!(
+            join_natural_sorted(values, " ").to_string(),
+            "1a 9 10 a a0 a1 a01 a001",
+        );
+
+        let value = join_natural_sorted(["v1.10.0", "v1.9.2", "v1.9.10"], ",");
+        assert_eq!(value.to_string(), "v1.9.2,v1.9.10,v1.10.0");
+
+        assert_eq!(join_natural_sorted([""; 0], ",").to_string(), "");
+    }
+
+    #[test]
+    fn preview() {
+        let more = |n| crate::concat_tuple((" (+", n, ")"));
+        let items = ["a", "b", "c", "d"];
+
+        let cases: &[(usize, &str)] = &[
+            (0, " (+4)"),
+            (1, "a (+3)"),
+            (3, "a, b, c (+1)"),
+            (4, "a, b, c, d"),
+            (9, "a, b, c, d"),
+        ];
+
+        for &(max, expected) in cases {
+            assert_eq!(
+                join_preview(items, ", ", max, more).to_string(),
+                expected,
+                "incorrect result for max {max}",
+            );
+        }
+
+        assert_eq!(join_preview([""; 0], ", ", 0, more).to_string(), "");
+    }
+
+    #[test]
+    fn quoted() {
+        use crate::{quote_backtick, quote_double};
+
+        let value = join_quoted(["a", "b", "c"], ", ", quote_double);
+        assert_eq!(value.to_string(), "\"a\", \"b\", \"c\"");
+
+        let value = join_quoted([1, 2], " ", quote_backtick);
+        assert_eq!(value.to_string(), "`1` `2`");
+
+        let value = join_quoted(["only"], ", ", quote_double);
+        assert_eq!(value.to_string(), "\"only\"");
+
+        let value = join_quoted([""; 0], ", ", quote_double);
+        assert_eq!(value.to_string(), "");
+    }
+
+    #[test]
+    fn compact() {
+        let cases: &[(&[&str], &str)] = &[
+            (&[], ""),
+            (&[""], ""),
+            (&["", ""], ""),
+            (&["a"], "a"),
+            (&["a", "", "c"], "a,c"),
+            (&["", "a", "b"], "a,b"),
+            (&["a", "b", ""], "a,b"),
+            (&["", "", "a", "", ""], "a"),
+        ];
+
+        for &(items, expected) in cases {
+            assert_eq!(
+                join_compact(items, ",").to_string(),
+                expected,
+                "incorrect result for {items:?}",
+            );
+        }
+
+        let value = join_compact(0..5, "+");
+        assert_eq!(value.to_string(), "0+1+2+3+4");
+
+        let evens = (1..=5).map(|n| crate::cond(n % 2 == 0, n));
+        assert_eq!(join_compact(evens, ",").to_string(), "2,4");
+    }
+
+    #[test]
+    fn every() {
+        let value = join_every(1..=12, ",", 5, "\n");
+        assert_eq!(value.to_string(), "1,2,3,4,5\n6,7,8,9,10\n11,12");
+
+        let value = join_every(1..=10, ",", 5, "\n");
+        assert_eq!(value.to_string(), "1,2,3,4,5\n6,7,8,9,10");
+
+        let value = join_every(1..=4, ",", 1, " | ");
+        assert_eq!(value.to_string(), "1 | 2 | 3 | 4");
+
+        let value = join_every(1..=4, ",", 0, " | ");
+        assert_eq!(value.to_string(), "1,2,3,4");
+
+        let value = join_every(0..0, ",", 5, "\n");
+        assert_eq!(value.to_string(), "");
+    }
+
+    #[test]
+    fn sparse() {
+        let value = fill_sparse([(2, "c"), (3, "d"), (5, "f")], "_", " ");
+        assert_eq!(value.to_string(), "_ _ c d _ f");
+
+        let value = fill_sparse([(0, 'a'), (1, 'b'), (2, 'c')], "_", " ");
+        assert_eq!(value.to_string(), "a b c");
+
+        let value = fill_sparse([(0, 'a'), (0, 'b'), (2, 'c')], "_", " ");
+        assert_eq!(value.to_string(), "a b _ c");
+
+        let value = fill_sparse([(0, ""); 0], "_", " ");
+        assert_eq!(value.to_string(), "");
+    }
+
+    #[test]
+    fn chunks() {
+        let cases: &[(usize, &str)] = &[
+            (0, "1,2,3,4,5,6"),
+            (1, "1; 2; 3; 4; 5; 6"),
+            (2, "1,2; 3,4; 5,6"),
+            (4, "1,2,3,4; 5,6"),
+            (6, "1,2,3,4,5,6"),
+            (7, "1,2,3,4,5,6"),
+        ];
+
+        for &(size, expected) in cases {
+            assert_eq!(
+                join_every(1..=6, ",", size, "; ").to_string(),
+                expected,
+                "incorrect result for chunk size {size}",
+            );
+        }
+
+        assert_eq!(join_every(0..0, ",", 2, "; ").to_string(), "");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn rev_collect() {
+        let count_up = |from: u32| {
+            let mut n = 0;
+            core::iter::from_fn(move || {
+                n += 1;
+                if n <= from {
+                    Some(n)
+                } else {
+                    None
+                }
+            })
+        };
+
+        let value = join_rev_collect(count_up(5), " ");
+        assert_eq!(value.to_string(), "5 4 3 2 1");
+        assert_eq!(
+            format!("{:?}", join_rev_collect(["a", "b"], ",")),
+            "\"b\",\"a\""
+        );
+
+        assert_eq!(join_rev_collect(count_up(1), " ").to_string(), "1");
+        assert_eq!(join_rev_collect(count_up(0), " ").to_string(), "");
+    }
+
+    #[test]
+    fn ranges() {
+        let cases: &[(&[u64], &str)] = &[
+            (&[], ""),
+            (&[4], "4"),
+            (&[4, 5], "4-5"),
+            (&[1, 3, 5], "1,3,5"),
+            (&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10], "1-10"),
+            (&[0, 1, 2, 10, 20, 21], "0-2,10,20-21"),
+            (&[3, 3, 4], "3,3-4"),
+            (
+                &[u64::MAX - 1, u64::MAX],
+                "18446744073709551614-18446744073709551615",
+            ),
+        ];
+
+        for &(items, expected) in cases {
+            assert_eq!(
+                join_ranges(items.iter().copied(), ",").to_string(),
+                expected,
+                "incorrect result for {items:?}",
+            );
+        }
+    }
+
+    #[test]
+    fn results() {
+        let summary = |n, err: &&'static str| {
+            crate::concat_tuple((" [", n, ": ", *err, "]"))
+        };
+
+        let value =
+            join_results([Ok(1), Err("x"), Ok(2), Err("y")], "+", summary);
+        assert_eq!(value.to_string(), "1+2 [2: x]");
+
+        let value = join_results([Ok::<_, &str>(1), Ok(2)], "+", summary);
+        assert_eq!(value.to_string(), "1+2");
+
+        let value = join_results([Err::<u8, _>("x"), Err("y")], "+", summary);
+        assert_eq!(value.to_string(), " [2: x]");
+
+        let value = join_results([Ok::<u8, &str>(1); 0], "+", summary);
+        assert_eq!(value.to_string(), "");
+    }
+
+    #[test]
+    fn strict() {
+        /// Writes its text, or partial output followed by an error.
+        #[derive(Clone, Copy, Debug)]
+        struct Item(Option<&'static str>);
+
+        impl Display for Item {
+            fn fmt(&self, f: &mut Formatter) -> Result {
+                match self.0 {
+                    Some(s) => f.write_str(s),
+                    None => {
+                        f.write_str("!")?;
+                        Err(Error)
+                    }
+                }
+            }
+        }
+
+        const A: Item = Item(Some("a"));
+        const B: Item = Item(Some("b"));
+        const EMPTY: Item = Item(Some(""));
+        const FAIL: Item = Item(None);
+
+        let cases: &[(&[Item], &str)] = &[
+            (&[], ""),
+            (&[EMPTY], ""),
+            (&[FAIL], ""),
+            (&[EMPTY, EMPTY], ""),
+            (&[FAIL, EMPTY, FAIL], ""),
+            (&[A], "a"),
+            (&[A, B], "a,b"),
+            (&[EMPTY, A, B], "a,b"),
+            (&[FAIL, A, B], "a,b"),
+            (&[A, B, EMPTY], "a,b"),
+            (&[A, B, FAIL], "a,b"),
+            (&[A, EMPTY, B], "a,b"),
+            (&[A, FAIL, B], "a,b"),
+            (&[A, EMPTY, FAIL, EMPTY, B], "a,b"),
+            (&[FAIL, EMPTY, A, FAIL, EMPTY], "a"),
+        ];
+
+        for &(items, expected) in cases {
+            assert_eq!(
+                join_strict(items, ",").to_string(),
+                expected,
+                "incorrect result for {items:?}",
+            );
+        }
+
+        let value = join_strict(0..3, ", ");
+        assert_eq!(value.to_string(), "0, 1, 2");
+    }
+
+    #[test]
+    fn english() {
+        let items = ["a", "b", "c", "d"];
+        let expected = ["", "a", "a and b", "a, b, and c", "a, b, c, and d"];
+
+        for (len, expected) in expected.iter().enumerate() {
+            assert_eq!(
+                list_english(&items[..len]).to_string(),
+                *expected,
+                "incorrect result for length {len}",
+            );
+        }
+
+        let value = list_english((1..=3).filter(|n| n % 2 == 1));
+        assert_eq!(value.to_string(), "1 and 3");
+    }
+
+    #[test]
+    fn map_truncate() {
+        let items = ["a", "abcdef", "", "abcdefghij"];
+
+        assert_eq!(join_map_truncate(items, ",", 3).to_string(), "a,ab…,,ab…");
+        assert_eq!(
+            join_map_truncate(items, ",", 6).to_string(),
+            "a,abcdef,,abcde…",
+        );
+        assert_eq!(
+            join_map_truncate(items, ",", 10).to_string(),
+            "a,abcdef,,abcdefghij",
+        );
+        assert_eq!(join_map_truncate(items, ",", 0).to_string(), ",,,");
+
+        let value = join_map_truncate([12345, 1], " ", 4);
+        assert_eq!(value.to_string(), "123… 1");
+    }
+
+    #[test]
+    fn alt_sep() {
+        let items = ["a", "b", "c", "d"];
+        let expected = ["", "a", "a-b", "a-b+c", "a-b+c-d"];
+
+        for (len, expected) in expected.iter().enumerate() {
+            assert_eq!(
+                join_alt_sep(&items[..len], '-', '+').to_string(),
+                *expected,
+                "incorrect result for length {len}",
+            );
+        }
+
+        let value = join_alt_sep(1..=5, ": ", ", ");
+        assert_eq!(value.to_string(), "1: 2, 3: 4, 5");
+    }
+
+    #[test]
+    fn breadcrumbs() {
+        let trail = ["Home", "Settings", "Privacy"];
+
+        assert_eq!(breadcrumb(trail).to_string(), "Home › Settings › Privacy");
+        assert_eq!(
+            breadcrumb_sep(trail, " > ").to_string(),
+            "Home > Settings > Privacy",
+        );
+        assert_eq!(breadcrumb(&trail[..1]).to_string(), "Home");
+        assert_eq!(breadcrumb([""; 0]).to_string(), "");
+
+        let value = breadcrumb(["Q&A", "<new>"].iter().map(crate::escape_html));
+        assert_eq!(value.to_string(), "Q&amp;A › &lt;new&gt;");
+    }
+
+    #[test]
+    fn skip() {
+        let cases: &[(&[i32], &str)] = &[
+            (&[], ""),
+            (&[0], ""),
+            (&[0, 0], ""),
+            (&[1], "1"),
+            (&[0, 1, 2], "1,2"),
+            (&[1, 2, 0], "1,2"),
+            (&[1, 0, 2], "1,2"),
+            (&[0, 1, 0, 0, 2, 0], "1,2"),
+            (&[1, 2, 3], "1,2,3"),
+        ];
+
+        for &(items, expected) in cases {
+            assert_eq!(
+                join_skip(items, ",", |&&n| n == 0).to_string(),
+                expected,
+                "incorrect result for {items:?}",
+            );
+        }
+
+        // Items that write nothing are not skipped.
+        let value = join_skip(["a", "", "b"], ",", |_| false);
+        assert_eq!(value.to_string(), "a,,b");
+    }
+
+    #[test]
+    fn head_tail() {
+        let cases = [
+            (0u8, ""),
+            (1, "1"),
+            (4, "1,2,3,4"),
+            (5, "1,2,…,4,5"),
+            (10, "1,2,…,9,10"),
+        ];
+
+        for (len, expected) in cases {
+            assert_eq!(
+                join_head_tail(1..=len, ",", 2, 2, "…").to_string(),
+                expected,
+                "incorrect result for length {len}",
+            );
+        }
+
+        // Only one side.
+        assert_eq!(
+            join_head_tail(1u8..=5, ",", 0, 2, "…").to_string(),
+            "…,4,5"
+        );
+        assert_eq!(
+            join_head_tail(1u8..=5, ",", 2, 0, "…").to_string(),
+            "1,2,…"
+        );
+        assert_eq!(join_head_tail(1u8..=5, ",", 0, 0, "…").to_string(), "…");
+        assert_eq!(
+            join_head_tail(1u8..=5, ",", usize::MAX, 1, "…").to_string(),
+            "1,2,3,4,5",
+        );
+    }
+
+    #[test]
+    fn conjunctions() {
+        let items = ["a", "b", "c", "d"];
+        let cases = [
+            (
+                "and",
+                true,
+                ["", "a", "a and b", "a, b, and c", "a, b, c, and d"],
+            ),
+            ("and", false, ["", "a", "a and b", "a, b and c", "a, b, c and d"]),
+            ("or", true, ["", "a", "a or b", "a, b, or c", "a, b, c, or d"]),
+            ("or", false, ["", "a", "a or b", "a, b or c", "a, b, c or d"]),
+        ];
+
+        for (conj, oxford, expected) in cases {
+            for (len, expected) in expected.iter().enumerate() {
+                assert_eq!(
+                    conjunction_list(&items[..len], conj, oxford).to_string(),
+                    *expected,
+                    "incorrect result for {conj:?}, {oxford}, length {len}",
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn paged() {
+        let page_sep = |n| crate::concat_tuple((" | p", n, ": "));
+
+        let cases = [
+            (0, ""),
+            (2, "1,2"),
+            (3, "1,2 | p2: 3"),
+            (4, "1,2 | p2: 3,4"),
+            (5, "1,2 | p2: 3,4 | p3: 5"),
+        ];
+
+        for (len, expected) in cases {
+            assert_eq!(
+                join_paged(1..=len, 2, ',', page_sep).to_string(),
+                expected,
+                "incorrect result for length {len}",
+            );
+        }
+
+        assert_eq!(join_paged(1..=3, 0, ',', page_sep).to_string(), "1,2,3");
+        assert_eq!(
+            join_paged(1..=3, 1, ',', page_sep).to_string(),
+            "1 | p2: 2 | p3: 3",
+        );
+    }
+
+    #[test]
+    fn skip_empty_edges() {
+        let cases: &[(&[&str], &str)] = &[
+            (&[], ""),
+            (&[""], ""),
+            (&["", ""], ""),
+            (&["a"], "a"),
+            (&["", "a"], "a"),
+            (&["a", ""], "a"),
+            (&["", "", "a", "", ""], "a"),
+            (&["a", "", "b"], "a, , b"),
+            (&["", "a", "b", ""], "a, b"),
+            (&["", "a", "", "", "b", ""], "a, , , b"),
+        ];
+
+        for &(items, expected) in cases {
+            assert_eq!(
+                join_skip_empty_edges(items, ", ").to_string(),
+                expected,
+                "incorrect result for {items:?}",
+            );
+        }
+
+        let value = join_skip_empty_edges(
+            [
+                crate::concat_tuple(("", "")),
+                crate::concat_tuple(("a", "")),
+                crate::concat_tuple(("", "b")),
+                crate::concat_tuple(("", "")),
+            ],
+            "|",
+        );
+        assert_eq!(value.to_string(), "a|b");
+    }
+}
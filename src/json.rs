@@ -0,0 +1,136 @@
+use core::fmt::*;
+
+pub(crate) mod types {
+    #[allow(unused)]
+    use super::*;
+
+    /// See [`json_number_array()`].
+    #[derive(Clone, Copy)]
+    pub struct JsonNumberArray<I> {
+        pub(super) iter: I,
+    }
+
+    /// See [`json_array_with()`].
+    #[derive(Clone, Copy)]
+    pub struct JsonArrayWith<I, F> {
+        pub(super) iter: I,
+        pub(super) item: F,
+    }
+}
+
+use types::*;
+
+/// Writes [`Iterator`] items as a compact JSON array of numbers.
+///
+/// Items are written unquoted via [`Display`], so they should be numbers that
+/// are valid in JSON. Notably, non-finite floats like [`f64::NAN`] are not.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::json_number_array([1, 2, 3]);
+/// assert_eq!(value.to_string(), "[1,2,3]");
+///
+/// let value = fmty::json_number_array([0.5; 0]);
+/// assert_eq!(value.to_string(), "[]");
+/// ```
+pub fn json_number_array<I>(iter: I) -> JsonNumberArray<I::IntoIter>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+{
+    JsonNumberArray { iter: iter.into_iter() }
+}
+
+/// Writes [`Iterator`] items as a compact JSON array, with each item written
+/// by `item`.
+///
+/// The result of `item` is written as-is, so it must produce valid JSON.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::json_array_with([true, false], |b| if b { 1 } else { 0 });
+/// assert_eq!(value.to_string(), "[1,0]");
+///
+/// let value = fmty::json_array_with(["a", "b"], fmty::quote_double);
+/// assert_eq!(value.to_string(), r#"["a","b"]"#);
+/// ```
+pub fn json_array_with<I, R, F>(
+    iter: I,
+    item: F,
+) -> JsonArrayWith<I::IntoIter, F>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+    F: Fn(I::Item) -> R,
+{
+    JsonArrayWith { iter: iter.into_iter(), item }
+}
+
+/// Writes items as a JSON array.
+fn write_array<I>(f: &mut Formatter, iter: I) -> Result
+where
+    I: Iterator,
+    I::Item: Display,
+{
+    f.write_char('[')?;
+
+    for (i, item) in iter.enumerate() {
+        if i != 0 {
+            f.write_char(',')?;
+        }
+        write!(f, "{}", item)?;
+    }
+
+    f.write_char(']')
+}
+
+impl<I> Display for JsonNumberArray<I>
+where
+    I: Iterator + Clone,
+    I::Item: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write_array(f, self.iter.clone())
+    }
+}
+
+impl<I, R, F> Display for JsonArrayWith<I, F>
+where
+    I: Iterator + Clone,
+    R: Display,
+    F: Fn(I::Item) -> R,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write_array(f, self.iter.clone().map(&self.item))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers() {
+        assert_eq!(json_number_array([0u8; 0]).to_string(), "[]");
+        assert_eq!(json_number_array([7]).to_string(), "[7]");
+        assert_eq!(json_number_array([-1, 0, 1]).to_string(), "[-1,0,1]");
+        assert_eq!(json_number_array([1.5, 2.25]).to_string(), "[1.5,2.25]");
+        assert_eq!(json_number_array(1..=3).to_string(), "[1,2,3]");
+    }
+
+    #[test]
+    fn with() {
+        let value = json_array_with(1..=3, |n| json_number_array(0..n));
+        assert_eq!(value.to_string(), "[[0],[0,1],[0,1,2]]");
+
+        let value = json_array_with([None, Some(2)], |n| {
+            crate::cond_option_or(n, "null")
+        });
+        assert_eq!(value.to_string(), "[null,2]");
+
+        let value = json_array_with([""; 0], crate::quote_double);
+        assert_eq!(value.to_string(), "[]");
+    }
+}
@@ -1,24 +1,57 @@
 #![doc = include_str!("../README.md")]
 #![doc(test(attr(deny(warnings))))]
-#![cfg_attr(not(test), no_std)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 #[macro_use]
 mod macros;
 
+mod ansi;
+mod bridge;
+mod buf;
+mod bytes;
+mod checksum;
+mod cmp;
 mod concat;
 mod cond;
 mod convert_case;
+mod csv;
+mod duration;
+mod encoding;
+mod escape;
 mod fmt;
 mod fmt_iterator;
 mod fmt_with;
 mod format_args;
+mod grid;
+mod html;
+mod indent;
 mod infix;
 mod join;
+mod json;
+mod limit;
+mod lines;
+mod list;
+mod map;
+mod map_chars;
+mod margin;
+mod markdown;
+mod mask;
+mod measure;
+mod morse;
+mod net;
 mod no_op;
+mod number;
 mod once;
+mod pad;
 mod quote;
 mod repeat;
+mod spec;
 mod truncate;
+mod whitespace;
+mod wrap;
 
 /// Types defined by this crate.
 ///
@@ -27,14 +60,26 @@ mod truncate;
 pub mod types {
     #[doc(inline)]
     pub use crate::{
-        concat::types::*, cond::types::*, convert_case::types::*,
-        fmt_with::types::*, infix::types::*, join::types::*, no_op::types::*,
-        repeat::types::*, truncate::types::*,
+        ansi::types::*, bridge::types::*, bytes::types::*, checksum::types::*,
+        cmp::types::*, concat::types::*, cond::types::*,
+        convert_case::types::*, csv::types::*, duration::types::*,
+        encoding::types::*, escape::types::*, fmt_with::types::*,
+        grid::types::*, html::types::*, indent::types::*, infix::types::*,
+        join::types::*, json::types::*, limit::types::*, lines::types::*,
+        list::types::*, map::types::*, map_chars::types::*, margin::types::*,
+        markdown::types::*, mask::types::*, measure::types::*, morse::types::*,
+        net::types::*, no_op::types::*, number::types::*, pad::types::*,
+        repeat::types::*, spec::types::*, truncate::types::*,
+        whitespace::types::*, wrap::types::*,
     };
 }
 
 pub use crate::{
-    concat::*, cond::*, convert_case::*, fmt_iterator::*, fmt_with::*,
-    format_args as fmt_args, infix::*, join::*, no_op::*, quote::*, repeat::*,
-    truncate::*,
+    ansi::*, bridge::*, bytes::*, checksum::*, cmp::*, concat::*, cond::*,
+    convert_case::*, csv::*, duration::*, encoding::*, escape::*,
+    fmt_iterator::*, fmt_with::*, format_args as fmt_args, grid::*, html::*,
+    indent::*, infix::*, join::*, json::*, limit::*, lines::*, list::*, map::*,
+    map_chars::*, margin::*, markdown::*, mask::*, measure::*, morse::*,
+    net::*, no_op::*, number::*, pad::*, quote::*, repeat::*, spec::*,
+    truncate::*, whitespace::*, wrap::*,
 };
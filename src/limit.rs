@@ -0,0 +1,126 @@
+use core::{cell::Cell, fmt::*};
+
+pub(crate) mod types {
+    #[allow(unused)]
+    use super::*;
+
+    /// See [`with_depth_limit()`].
+    #[derive(Clone)]
+    pub struct DepthLimit<T> {
+        pub(super) value: T,
+        pub(super) max_depth: usize,
+        pub(super) depth: Cell<usize>,
+    }
+}
+
+use types::*;
+
+/// Writes `…` instead of a value once formatting recurses past `max_depth`.
+///
+/// [`Formatter`] cannot carry user state, so the current depth is tracked by an
+/// interior-mutable counter within [`DepthLimit`]. Each call to
+/// [`Display::fmt()`] increments the counter for the duration of formatting the
+/// inner value. If the inner value formats this same [`DepthLimit`] (such as
+/// through an [`Rc`](https://doc.rust-lang.org/std/rc/struct.Rc.html) cycle),
+/// the nested call observes the increased depth. As a result, [`DepthLimit`]
+/// does not implement [`Sync`].
+///
+/// This makes it safe to format self-referential structures with
+/// [`fmt_with()`](crate::fmt_with()) without overflowing the stack.
+///
+/// # Examples
+///
+/// ```
+/// use std::{fmt::Display, rc::{Rc, Weak}};
+///
+/// let value = Rc::new_cyclic(|this: &Weak<_>| {
+///     let this = Weak::clone(this) as Weak<dyn Display>;
+///
+///     fmty::with_depth_limit(
+///         fmty::fmt_with(move |f| match this.upgrade() {
+///             Some(this) => write!(f, "[{this}]"),
+///             None => Ok(()),
+///         }),
+///         2,
+///     )
+/// });
+///
+/// assert_eq!(value.to_string(), "[[…]]");
+/// ```
+pub fn with_depth_limit<T>(value: T, max_depth: usize) -> DepthLimit<T> {
+    DepthLimit { value, max_depth, depth: Cell::new(0) }
+}
+
+impl<T> DepthLimit<T> {
+    fn fmt_with(
+        &self,
+        f: &mut Formatter,
+        fmt: impl FnOnce(&T, &mut Formatter) -> Result,
+    ) -> Result {
+        /// Restores the previous depth, even if formatting panics.
+        struct Guard<'a>(&'a Cell<usize>);
+
+        impl Drop for Guard<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() - 1);
+            }
+        }
+
+        let depth = self.depth.get();
+        if depth >= self.max_depth {
+            return f.write_char('…');
+        }
+
+        self.depth.set(depth + 1);
+        let _guard = Guard(&self.depth);
+
+        fmt(&self.value, f)
+    }
+}
+
+impl<T: Debug> Debug for DepthLimit<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        self.fmt_with(f, Debug::fmt)
+    }
+}
+
+impl<T: Display> Display for DepthLimit<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        self.fmt_with(f, Display::fmt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::{Rc, Weak};
+
+    use super::*;
+    use crate::fmt_with;
+
+    /// Formats a structure that contains itself.
+    #[test]
+    fn self_referential() {
+        let rc = Rc::new_cyclic(|rc: &Weak<_>| {
+            let rc = Weak::clone(rc) as Weak<dyn Display>;
+
+            with_depth_limit(
+                fmt_with(move |f| {
+                    let rc = rc.upgrade().expect("`Rc` should be initialized");
+                    write!(f, "({rc})")
+                }),
+                3,
+            )
+        });
+
+        assert_eq!(rc.to_string(), "(((…)))");
+
+        // Depth is restored after formatting.
+        assert_eq!(rc.to_string(), "(((…)))");
+    }
+
+    #[test]
+    fn zero_depth() {
+        assert_eq!(with_depth_limit("hola", 0).to_string(), "…");
+        assert_eq!(with_depth_limit("hola", 1).to_string(), "hola");
+    }
+}
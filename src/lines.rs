@@ -0,0 +1,246 @@
+use core::fmt::*;
+
+pub(crate) mod types {
+    #[allow(unused)]
+    use super::*;
+
+    /// See [`number_nonblank_lines()`].
+    #[derive(Clone, Copy)]
+    pub struct NumberNonblankLines<T> {
+        pub(super) value: T,
+        pub(super) start: usize,
+    }
+
+    /// See [`every_nth_line()`].
+    #[cfg(feature = "alloc")]
+    #[derive(Clone, Copy)]
+    pub struct EveryNthLine<T, F> {
+        pub(super) value: T,
+        pub(super) n: usize,
+        pub(super) map: F,
+    }
+}
+
+use types::*;
+
+/// Numbers each non-empty line, starting at `start`, like `cat -b`.
+///
+/// Each number is right-aligned to 6 columns and followed by a tab. Empty lines
+/// are written unchanged and do not advance the count.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::number_nonblank_lines("fn main() {\n\n}", 1);
+/// assert_eq!(value.to_string(), "     1\tfn main() {\n\n     2\t}");
+/// ```
+pub fn number_nonblank_lines<T>(
+    value: T,
+    start: usize,
+) -> NumberNonblankLines<T> {
+    NumberNonblankLines { value, start }
+}
+
+/// Writes every `n`th line through `map`, such as to highlight it.
+///
+/// Lines are counted from 1, so the `n`th, `2n`th, and so on are mapped. If `n`
+/// is 0, no lines are mapped. Each mapped line is given to `map` without its
+/// `\n`, along with the [`Formatter`] to write it to. Text after a trailing
+/// `\n` is not counted as a line.
+///
+/// Each mapped line is buffered into a [`String`], which requires the `alloc`
+/// feature. Other lines are written as they are formatted.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::every_nth_line("a\nb\nc\nd", 2, |f, line| {
+///     write!(f, "{}", fmty::bold(line))
+/// });
+/// assert_eq!(value.to_string(), "a\n\x1b[1mb\x1b[0m\nc\n\x1b[1md\x1b[0m");
+/// ```
+///
+/// [`String`]: alloc::string::String
+#[cfg(feature = "alloc")]
+pub fn every_nth_line<T, F>(value: T, n: usize, map: F) -> EveryNthLine<T, F>
+where
+    F: Fn(&mut Formatter, &str) -> Result,
+{
+    EveryNthLine { value, n, map }
+}
+
+impl<T: Display> Display for NumberNonblankLines<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        struct Writer<'a, 'b> {
+            f: &'a mut Formatter<'b>,
+            next: usize,
+            at_line_start: bool,
+        }
+
+        impl Write for Writer<'_, '_> {
+            fn write_str(&mut self, s: &str) -> Result {
+                for (i, line) in s.split('\n').enumerate() {
+                    if i != 0 {
+                        self.f.write_char('\n')?;
+                        self.at_line_start = true;
+                    }
+
+                    if !line.is_empty() {
+                        if self.at_line_start {
+                            write!(self.f, "{:>6}\t", self.next)?;
+                            self.next += 1;
+                            self.at_line_start = false;
+                        }
+                        self.f.write_str(line)?;
+                    }
+                }
+                Ok(())
+            }
+        }
+
+        let mut writer = Writer { f, next: self.start, at_line_start: true };
+        write!(writer, "{}", self.value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, F> Display for EveryNthLine<T, F>
+where
+    T: Display,
+    F: Fn(&mut Formatter, &str) -> Result,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        use alloc::string::String;
+
+        struct Writer<'a, 'b, F> {
+            f: &'a mut Formatter<'b>,
+            n: usize,
+            map: &'a F,
+
+            // The 1-based number of the current line.
+            line: usize,
+
+            // The current line if it is mapped.
+            buf: String,
+        }
+
+        impl<F> Writer<'_, '_, F>
+        where
+            F: Fn(&mut Formatter, &str) -> Result,
+        {
+            fn is_mapped(&self) -> bool {
+                self.n != 0 && self.line % self.n == 0
+            }
+
+            fn end_line(&mut self) -> Result {
+                if self.is_mapped() {
+                    (self.map)(self.f, &self.buf)?;
+                    self.buf.clear();
+                }
+                self.line += 1;
+                Ok(())
+            }
+        }
+
+        impl<F> Write for Writer<'_, '_, F>
+        where
+            F: Fn(&mut Formatter, &str) -> Result,
+        {
+            fn write_str(&mut self, s: &str) -> Result {
+                for (i, part) in s.split('\n').enumerate() {
+                    if i != 0 {
+                        self.end_line()?;
+                        self.f.write_char('\n')?;
+                    }
+
+                    if self.is_mapped() {
+                        self.buf.push_str(part);
+                    } else {
+                        self.f.write_str(part)?;
+                    }
+                }
+                Ok(())
+            }
+        }
+
+        let mut writer = Writer {
+            f,
+            n: self.n,
+            map: &self.map,
+            line: 1,
+            buf: String::new(),
+        };
+        write!(writer, "{}", self.value)?;
+
+        if writer.buf.is_empty() {
+            Ok(())
+        } else {
+            writer.end_line()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nonblank() {
+        let cases = [
+            ("", ""),
+            ("a", "     1\ta"),
+            ("a\n", "     1\ta\n"),
+            ("\n", "\n"),
+            ("a\nb", "     1\ta\n     2\tb"),
+            ("\n\na\n\nb\n\n", "\n\n     1\ta\n\n     2\tb\n\n"),
+            (" \n", "     1\t \n"),
+        ];
+
+        for (value, expected) in cases {
+            assert_eq!(number_nonblank_lines(value, 1).to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn nonblank_parts() {
+        let value = crate::concat_tuple(("on", "e\n", '\n', "\ntw", 'o', "\n"));
+
+        assert_eq!(
+            number_nonblank_lines(value, 9).to_string(),
+            "     9\tone\n\n\n    10\ttwo\n",
+        );
+        assert_eq!(
+            number_nonblank_lines(value, 999_999).to_string(),
+            "999999\tone\n\n\n1000000\ttwo\n",
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn every_third() {
+        let mark = |f: &mut Formatter, line: &str| write!(f, "[{}]", line);
+
+        let cases = [
+            ("", ""),
+            ("a\nb", "a\nb"),
+            ("a\nb\nc", "a\nb\n[c]"),
+            ("a\nb\nc\n", "a\nb\n[c]\n"),
+            ("a\nb\nc\nd\ne\nf\ng", "a\nb\n[c]\nd\ne\n[f]\ng"),
+            ("\n\n\n\n\n\n", "\n\n[]\n\n\n[]\n"),
+        ];
+
+        for (value, expected) in cases {
+            assert_eq!(every_nth_line(value, 3, mark).to_string(), expected);
+        }
+
+        let value =
+            crate::concat_tuple(("a\nb", "\nc", 'c', "c\nd\ne\nf", "f"));
+        assert_eq!(
+            every_nth_line(value, 3, mark).to_string(),
+            "a\nb\n[ccc]\nd\ne\n[ff]",
+        );
+
+        assert_eq!(every_nth_line("a\nb\nc", 0, mark).to_string(), "a\nb\nc");
+        assert_eq!(every_nth_line("a\nb", 1, mark).to_string(), "[a]\n[b]");
+    }
+}
@@ -0,0 +1,150 @@
+use core::fmt::*;
+
+pub(crate) mod types {
+    #[allow(unused)]
+    use super::*;
+
+    /// See [`nested_list()`].
+    #[derive(Clone, Copy)]
+    pub struct NestedList<I, F> {
+        pub(super) iter: I,
+        pub(super) render: F,
+        pub(super) depth: usize,
+    }
+}
+
+use types::*;
+
+/// Writes a bulleted list where each item can have its own list of children.
+///
+/// `render` maps each item to its label and children, which are written below
+/// it one level deeper. Each level is indented by two spaces and cycles through
+/// the bullets `-`, `*`, and `+`. Items are written starting at level `depth`,
+/// which allows continuing a list nested within other output.
+///
+/// Items are separated by `\n`, without a trailing newline.
+///
+/// # Examples
+///
+/// ```
+/// struct Node(&'static str, &'static [Node]);
+///
+/// let tree = [
+///     Node("fruit", &[Node("apple", &[]), Node("pear", &[])]),
+///     Node("bread", &[]),
+/// ];
+///
+/// let value = fmty::nested_list(&tree, |node| (node.0, &node.1[..]), 0);
+/// assert_eq!(value.to_string(), "- fruit\n  * apple\n  * pear\n- bread");
+/// ```
+pub fn nested_list<I, F, T, C>(
+    items: I,
+    render: F,
+    depth: usize,
+) -> NestedList<I::IntoIter, F>
+where
+    I: IntoIterator,
+    F: Fn(I::Item) -> (T, C),
+{
+    NestedList { iter: items.into_iter(), render, depth }
+}
+
+impl<I, F, T, C> Display for NestedList<I, F>
+where
+    I: Iterator + Clone,
+    F: Fn(I::Item) -> (T, C),
+    T: Display,
+    C: IntoIterator<IntoIter = I>,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        /// Writes `iter` at `level`, then recursively writes each item's
+        /// children.
+        fn write_level<I, F, T, C>(
+            f: &mut Formatter,
+            iter: I,
+            render: &F,
+            level: usize,
+            first: &mut bool,
+        ) -> Result
+        where
+            I: Iterator,
+            F: Fn(I::Item) -> (T, C),
+            T: Display,
+            C: IntoIterator<IntoIter = I>,
+        {
+            const BULLETS: [char; 3] = ['-', '*', '+'];
+
+            for item in iter {
+                let (label, children) = render(item);
+
+                if !*first {
+                    f.write_char('\n')?;
+                }
+                *first = false;
+
+                for _ in 0..level {
+                    f.write_str("  ")?;
+                }
+                write!(f, "{} {}", BULLETS[level % BULLETS.len()], label)?;
+
+                write_level(f, children.into_iter(), render, level + 1, first)?;
+            }
+            Ok(())
+        }
+
+        let mut first = true;
+        write_level(f, self.iter.clone(), &self.render, self.depth, &mut first)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Node(&'static str, Vec<Node>);
+
+    fn leaf(name: &'static str) -> Node {
+        Node(name, Vec::new())
+    }
+
+    fn render(node: &Node) -> (&str, &[Node]) {
+        (node.0, &node.1)
+    }
+
+    #[test]
+    fn two_levels() {
+        let tree = vec![
+            Node("a", vec![leaf("a1"), leaf("a2")]),
+            leaf("b"),
+            Node("c", vec![leaf("c1")]),
+        ];
+
+        assert_eq!(
+            nested_list(&tree, render, 0).to_string(),
+            core::concat!(
+                "- a\n", "  * a1\n", "  * a2\n", "- b\n", "- c\n", "  * c1",
+            ),
+        );
+    }
+
+    #[test]
+    fn bullets_cycle() {
+        let tree =
+            [Node("1", vec![Node("2", vec![Node("3", vec![leaf("4")])])])];
+
+        assert_eq!(
+            nested_list(&tree, render, 0).to_string(),
+            "- 1\n  * 2\n    + 3\n      - 4",
+        );
+        assert_eq!(
+            nested_list(&tree, render, 1).to_string(),
+            "  * 1\n    + 2\n      - 3\n        * 4",
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let tree: [Node; 0] = [];
+        assert_eq!(nested_list(&tree, render, 0).to_string(), "");
+    }
+}
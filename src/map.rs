@@ -0,0 +1,124 @@
+use core::fmt::*;
+
+pub(crate) mod types {
+    #[allow(unused)]
+    use super::*;
+
+    /// See [`match_fmt()`].
+    #[derive(Clone, Copy)]
+    pub struct MatchFmt<'a, T: ?Sized, F> {
+        pub(super) value: &'a T,
+        pub(super) fmt: F,
+    }
+
+    /// See [`match_fmt_owned()`].
+    #[derive(Clone, Copy)]
+    pub struct MatchFmtOwned<T, F> {
+        pub(super) value: T,
+        pub(super) fmt: F,
+    }
+}
+
+use types::*;
+
+/// Formats a borrowed value via a closure.
+///
+/// This is like [`fmt_with()`](crate::fmt_with()), but the closure is given
+/// the value, which is convenient for writing different text per `enum`
+/// variant.
+///
+/// # Examples
+///
+/// ```
+/// enum Status {
+///     Ok,
+///     Failed(u32),
+/// }
+///
+/// let render = |status: &Status| {
+///     fmty::match_fmt(status, |status, f| match status {
+///         Status::Ok => f.write_str("ok"),
+///         Status::Failed(n) => write!(f, "failed {n} times"),
+///     })
+///     .to_string()
+/// };
+///
+/// assert_eq!(render(&Status::Ok), "ok");
+/// assert_eq!(render(&Status::Failed(3)), "failed 3 times");
+/// ```
+pub fn match_fmt<T, F>(value: &T, fmt: F) -> MatchFmt<'_, T, F>
+where
+    T: ?Sized,
+    F: Fn(&T, &mut Formatter) -> Result,
+{
+    MatchFmt { value, fmt }
+}
+
+/// Formats an owned value via a closure.
+///
+/// This is like [`match_fmt()`], but takes the value by value so that the
+/// result may outlive the value's original binding.
+///
+/// # Examples
+///
+/// ```
+/// #[derive(Clone, Copy)]
+/// enum Level {
+///     Info,
+///     Warn,
+/// }
+///
+/// fn label(level: Level) -> impl std::fmt::Display {
+///     fmty::match_fmt_owned(level, |level, f| match level {
+///         Level::Info => f.write_str("INFO"),
+///         Level::Warn => f.write_str("WARN"),
+///     })
+/// }
+///
+/// assert_eq!(label(Level::Info).to_string(), "INFO");
+/// assert_eq!(label(Level::Warn).to_string(), "WARN");
+/// ```
+pub fn match_fmt_owned<T, F>(value: T, fmt: F) -> MatchFmtOwned<T, F>
+where
+    F: Fn(&T, &mut Formatter) -> Result,
+{
+    MatchFmtOwned { value, fmt }
+}
+
+impl<T, F> Debug for MatchFmt<'_, T, F>
+where
+    T: ?Sized,
+    F: Fn(&T, &mut Formatter) -> Result,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        (self.fmt)(self.value, f)
+    }
+}
+
+impl<T, F> Display for MatchFmt<'_, T, F>
+where
+    T: ?Sized,
+    F: Fn(&T, &mut Formatter) -> Result,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        (self.fmt)(self.value, f)
+    }
+}
+
+impl<T, F> Debug for MatchFmtOwned<T, F>
+where
+    F: Fn(&T, &mut Formatter) -> Result,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        (self.fmt)(&self.value, f)
+    }
+}
+
+impl<T, F> Display for MatchFmtOwned<T, F>
+where
+    F: Fn(&T, &mut Formatter) -> Result,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        (self.fmt)(&self.value, f)
+    }
+}
@@ -0,0 +1,190 @@
+use core::fmt::*;
+
+pub(crate) mod types {
+    #[allow(unused)]
+    use super::*;
+
+    /// See [`caesar()`], [`rot13()`].
+    #[derive(Clone, Copy)]
+    pub struct Caesar<T> {
+        pub(super) value: T,
+        pub(super) shift: u8,
+    }
+
+    /// See [`map_matching()`].
+    #[derive(Clone, Copy)]
+    pub struct MapMatching<T, P, F> {
+        pub(super) value: T,
+        pub(super) pred: P,
+        pub(super) map: F,
+    }
+}
+
+use types::*;
+
+/// Shifts ASCII letters by 13, wrapping within each case.
+///
+/// Applying this twice results in the original value.
+///
+/// This is equivalent to <code>[caesar]\(value, 13\)</code>.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::rot13("Hello, World!");
+/// assert_eq!(value.to_string(), "Uryyb, Jbeyq!");
+/// ```
+pub fn rot13<T>(value: T) -> Caesar<T> {
+    caesar(value, 13)
+}
+
+/// Shifts ASCII letters by `shift`, wrapping within each case.
+///
+/// Non-letters are written unchanged.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::caesar("xyz ABC", 3);
+/// assert_eq!(value.to_string(), "abc DEF");
+/// ```
+pub fn caesar<T>(value: T, shift: u8) -> Caesar<T> {
+    Caesar { value, shift: shift % 26 }
+}
+
+/// Maps each [`char`] for which `pred` returns `true`, writing other [`char`]s
+/// unchanged.
+///
+/// # Examples
+///
+/// ```
+/// let is_vowel = |c| "aeiou".contains(c);
+/// let value = fmty::map_matching("hello world", is_vowel, |c| c.to_ascii_uppercase());
+/// assert_eq!(value.to_string(), "hEllO wOrld");
+///
+/// let value = fmty::map_matching("PIN 1234", |c| c.is_ascii_digit(), |_| '*');
+/// assert_eq!(value.to_string(), "PIN ****");
+/// ```
+pub fn map_matching<T, P, F>(value: T, pred: P, map: F) -> MapMatching<T, P, F>
+where
+    P: Fn(char) -> bool,
+    F: Fn(char) -> char,
+{
+    MapMatching { value, pred, map }
+}
+
+/// Writes each `char` through a mapping.
+pub(crate) struct MapCharsWriter<'a, 'b, F> {
+    pub(crate) f: &'a mut Formatter<'b>,
+    pub(crate) map: F,
+}
+
+impl<F: FnMut(char) -> char> Write for MapCharsWriter<'_, '_, F> {
+    fn write_str(&mut self, s: &str) -> Result {
+        for c in s.chars() {
+            self.write_char(c)?;
+        }
+        Ok(())
+    }
+
+    fn write_char(&mut self, c: char) -> Result {
+        self.f.write_char((self.map)(c))
+    }
+}
+
+impl<T> Caesar<T> {
+    fn writer<'a, 'b>(
+        &self,
+        f: &'a mut Formatter<'b>,
+    ) -> MapCharsWriter<'a, 'b, impl FnMut(char) -> char> {
+        let shift = self.shift;
+
+        let map = move |c: char| {
+            let base = match c {
+                'a'..='z' => b'a',
+                'A'..='Z' => b'A',
+                _ => return c,
+            };
+            (base + (c as u8 - base + shift) % 26) as char
+        };
+
+        MapCharsWriter { f, map }
+    }
+}
+
+impl<T: Debug> Debug for Caesar<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(self.writer(f), "{:?}", self.value)
+    }
+}
+
+impl<T: Display> Display for Caesar<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(self.writer(f), "{}", self.value)
+    }
+}
+
+impl<T, P, F> Display for MapMatching<T, P, F>
+where
+    T: Display,
+    P: Fn(char) -> bool,
+    F: Fn(char) -> char,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let map = |c| if (self.pred)(c) { (self.map)(c) } else { c };
+        write!(MapCharsWriter { f, map }, "{}", self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_strategy::proptest;
+
+    use super::*;
+
+    #[proptest]
+    fn rot13_twice(s: String) {
+        assert_eq!(rot13(rot13(&s)).to_string(), s);
+    }
+
+    #[proptest]
+    fn caesar_inverse(s: String, shift: u8) {
+        let shift = shift % 26;
+        assert_eq!(caesar(caesar(&s, shift), 26 - shift).to_string(), s);
+    }
+
+    #[test]
+    fn shifts() {
+        assert_eq!(rot13("abcxyz ABCXYZ 123").to_string(), "nopklm NOPKLM 123");
+        assert_eq!(caesar("abc", 0).to_string(), "abc");
+        assert_eq!(caesar("abc", 26).to_string(), "abc");
+        assert_eq!(caesar("Zz", 1).to_string(), "Aa");
+        assert_eq!(caesar("ü", 5).to_string(), "ü");
+        assert_eq!(format!("{:?}", rot13("a")), "\"n\"");
+    }
+
+    #[test]
+    fn matching() {
+        let is_vowel = |c: char| "aeiouAEIOU".contains(c);
+        let upper = |c: char| c.to_ascii_uppercase();
+
+        assert_eq!(map_matching("", is_vowel, upper).to_string(), "");
+        assert_eq!(
+            map_matching("rhythm", is_vowel, upper).to_string(),
+            "rhythm"
+        );
+        assert_eq!(
+            map_matching("Education", is_vowel, upper).to_string(),
+            "EdUcAtIOn",
+        );
+        assert_eq!(
+            map_matching(format_args!("{}{}", "queue", 42), is_vowel, upper)
+                .to_string(),
+            "qUEUE42",
+        );
+        assert_eq!(
+            map_matching("añejo", |c| !c.is_ascii(), |_| '?').to_string(),
+            "a?ejo",
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use core::fmt::*;
+
+use crate::pad::write_fill;
+
+pub(crate) mod types {
+    #[allow(unused)]
+    use super::*;
+
+    /// See [`margins()`].
+    #[derive(Clone, Copy)]
+    pub struct Margins<T> {
+        pub(super) value: T,
+        pub(super) top: usize,
+        pub(super) bottom: usize,
+    }
+}
+
+use types::*;
+
+/// Writes `top` newlines before a value and `bottom` newlines after it.
+///
+/// This spaces a block of text apart from its surroundings. For a value
+/// without a trailing newline, `bottom` must be at least 2 to leave a blank
+/// line below it.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::margins("Title", 1, 2);
+/// assert_eq!(value.to_string(), "\nTitle\n\n");
+/// ```
+pub fn margins<T>(value: T, top: usize, bottom: usize) -> Margins<T> {
+    Margins { value, top, bottom }
+}
+
+impl<T: Display> Display for Margins<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write_fill(f, '\n', self.top)?;
+        write!(f, "{}", self.value)?;
+        write_fill(f, '\n', self.bottom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn margin() {
+        let cases = [
+            (0, 0, "a\nb"),
+            (1, 0, "\na\nb"),
+            (0, 1, "a\nb\n"),
+            (2, 3, "\n\na\nb\n\n\n"),
+        ];
+
+        for (top, bottom, expected) in cases {
+            assert_eq!(margins("a\nb", top, bottom).to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(margins("", 0, 0).to_string(), "");
+        assert_eq!(margins("", 1, 2).to_string(), "\n\n\n");
+        assert_eq!(margins(margins("", 1, 0), 0, 1).to_string(), "\n\n");
+    }
+}
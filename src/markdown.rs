@@ -0,0 +1,112 @@
+use core::fmt::*;
+
+pub(crate) mod types {
+    #[allow(unused)]
+    use super::*;
+
+    /// See [`blockquote()`].
+    #[derive(Clone, Copy)]
+    pub struct Blockquote<T> {
+        pub(super) value: T,
+    }
+}
+
+use types::*;
+
+/// Writes a value as a Markdown blockquote by prefixing each line with `> `.
+///
+/// Blank lines are prefixed with `>` alone so that paragraphs stay within the
+/// same quote without trailing whitespace. A trailing newline is kept as-is.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::blockquote("Hello\n\nWorld\n");
+/// assert_eq!(value.to_string(), "> Hello\n>\n> World\n");
+/// ```
+pub fn blockquote<T>(value: T) -> Blockquote<T> {
+    Blockquote { value }
+}
+
+impl<T: Display> Display for Blockquote<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        struct Writer<'a, 'b> {
+            f: &'a mut Formatter<'b>,
+            at_line_start: bool,
+        }
+
+        impl Write for Writer<'_, '_> {
+            fn write_str(&mut self, s: &str) -> Result {
+                for (i, line) in s.split('\n').enumerate() {
+                    if i != 0 {
+                        if self.at_line_start {
+                            self.f.write_char('>')?;
+                        }
+                        self.f.write_char('\n')?;
+                        self.at_line_start = true;
+                    }
+
+                    if !line.is_empty() {
+                        if self.at_line_start {
+                            self.f.write_str("> ")?;
+                            self.at_line_start = false;
+                        }
+                        self.f.write_str(line)?;
+                    }
+                }
+                Ok(())
+            }
+        }
+
+        write!(Writer { f, at_line_start: true }, "{}", self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quote() {
+        let cases = [
+            ("", ""),
+            ("a", "> a"),
+            ("a\n", "> a\n"),
+            ("a\nb", "> a\n> b"),
+            ("\n", ">\n"),
+            ("\na", ">\n> a"),
+            ("a\n\n", "> a\n>\n"),
+        ];
+
+        for (value, expected) in cases {
+            assert_eq!(blockquote(value).to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn paragraphs() {
+        let value = blockquote(crate::concat_tuple((
+            "First line.\nSecond line.\n",
+            '\n',
+            "Next paragraph.",
+            "\n\n\nLast.\n",
+        )));
+
+        assert_eq!(
+            value.to_string(),
+            core::concat!(
+                "> First line.\n",
+                "> Second line.\n",
+                ">\n",
+                "> Next paragraph.\n",
+                ">\n",
+                ">\n",
+                "> Last.\n",
+            ),
+        );
+
+        // Nested quotes.
+        let value = blockquote(blockquote("a\n\nb"));
+        assert_eq!(value.to_string(), "> > a\n> >\n> > b");
+    }
+}
@@ -0,0 +1,106 @@
+use core::fmt::*;
+
+pub(crate) mod types {
+    #[allow(unused)]
+    use super::*;
+
+    /// See [`mask_email()`].
+    #[derive(Clone, Copy)]
+    pub struct MaskEmail<T> {
+        pub(super) value: T,
+    }
+}
+
+use types::*;
+
+/// Masks the local part of an email address, except for its first [`char`].
+///
+/// Each masked [`char`] is replaced with `*`. The domain begins after the last
+/// `@`. If there is no `@`, the entire value is masked.
+///
+/// Because the position of the last `@` must be known before writing, the
+/// value is formatted twice.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::mask_email("user@example.com");
+/// assert_eq!(value.to_string(), "u***@example.com");
+/// ```
+pub fn mask_email<T>(value: T) -> MaskEmail<T> {
+    MaskEmail { value }
+}
+
+impl<T: Display> Display for MaskEmail<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        /// Finds the `char` index of the last `@`.
+        struct Probe {
+            len: usize,
+            at: Option<usize>,
+        }
+
+        impl Write for Probe {
+            fn write_str(&mut self, s: &str) -> Result {
+                for c in s.chars() {
+                    if c == '@' {
+                        self.at = Some(self.len);
+                    }
+                    self.len += 1;
+                }
+                Ok(())
+            }
+        }
+
+        struct Writer<'a, 'b> {
+            f: &'a mut Formatter<'b>,
+            index: usize,
+            at: Option<usize>,
+        }
+
+        impl Write for Writer<'_, '_> {
+            fn write_str(&mut self, s: &str) -> Result {
+                for c in s.chars() {
+                    let index = self.index;
+                    self.index += 1;
+
+                    let keep = match self.at {
+                        Some(at) => index == 0 || index >= at,
+                        None => false,
+                    };
+                    self.f.write_char(if keep { c } else { '*' })?;
+                }
+                Ok(())
+            }
+        }
+
+        let mut probe = Probe { len: 0, at: None };
+        write!(probe, "{}", self.value)?;
+
+        write!(Writer { f, index: 0, at: probe.at }, "{}", self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typical() {
+        assert_eq!(mask_email("a@b.c").to_string(), "a@b.c");
+        assert_eq!(mask_email("ab@b.c").to_string(), "a*@b.c");
+        assert_eq!(
+            mask_email(format_args!("{}@{}", "jane.doe", "example.com"))
+                .to_string(),
+            "j*******@example.com",
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(mask_email("").to_string(), "");
+        assert_eq!(mask_email("nobody").to_string(), "******");
+        assert_eq!(mask_email("@example.com").to_string(), "@example.com");
+        assert_eq!(mask_email("a@b@c.d").to_string(), "a**@c.d");
+        assert_eq!(mask_email("üser@x").to_string(), "ü***@x");
+    }
+}
@@ -0,0 +1,265 @@
+use core::{fmt::*, result};
+
+use crate::ansi::StripAnsiWriter;
+
+pub(crate) mod types {
+    #[allow(unused)]
+    use super::*;
+
+    /// See [`with_word_count()`], [`with_char_count()`].
+    #[derive(Clone, Copy)]
+    pub struct WithCount<T> {
+        pub(super) value: T,
+        pub(super) words: bool,
+    }
+}
+
+use types::*;
+
+/// Counts written [`char`]s.
+struct CharCounter {
+    len: usize,
+}
+
+impl Write for CharCounter {
+    fn write_str(&mut self, s: &str) -> Result {
+        self.len += s.chars().count();
+        Ok(())
+    }
+
+    fn write_char(&mut self, _: char) -> Result {
+        self.len += 1;
+        Ok(())
+    }
+}
+
+/// Returns the number of [`char`]s written by `value`, excluding ANSI escape
+/// sequences.
+///
+/// This is the width of `value` in a terminal when every [`char`] takes one
+/// column, which makes it useful for aligning text styled by [`colored()`].
+/// Escape sequences are detected like in [`strip_ansi()`].
+///
+/// # Errors
+///
+/// Returns an error if formatting `value` fails.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::red("hola");
+/// assert_eq!(value.to_string().chars().count(), 13);
+/// assert_eq!(fmty::visible_width(&value), Ok(4));
+/// ```
+///
+/// [`colored()`]: crate::colored()
+/// [`strip_ansi()`]: crate::strip_ansi()
+pub fn visible_width<T: Display + ?Sized>(
+    value: &T,
+) -> result::Result<usize, Error> {
+    let mut counter = CharCounter { len: 0 };
+    let mut writer = StripAnsiWriter::new(&mut counter);
+    write!(writer, "{}", value)?;
+    writer.finish()?;
+    Ok(counter.len)
+}
+
+/// Writes a value followed by the number of words it contains, like
+/// `" (3 words)"`.
+///
+/// Words are runs of non-whitespace [`char`]s. The count is taken as the value
+/// is written, so the value is only formatted once.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::with_word_count("the quick  brown\nfox");
+/// assert_eq!(value.to_string(), "the quick  brown\nfox (4 words)");
+/// ```
+pub fn with_word_count<T>(value: T) -> WithCount<T> {
+    WithCount { value, words: true }
+}
+
+/// Writes a value followed by the number of [`char`]s it contains, like
+/// `" (5 chars)"`.
+///
+/// See [`with_word_count()`] for details.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::with_char_count("héllo");
+/// assert_eq!(value.to_string(), "héllo (5 chars)");
+/// ```
+pub fn with_char_count<T>(value: T) -> WithCount<T> {
+    WithCount { value, words: false }
+}
+
+/// Returns the number of [`char`]s written by `value`.
+pub(crate) fn char_len<T: Display + ?Sized>(
+    value: &T,
+) -> result::Result<usize, Error> {
+    let mut counter = CharCounter { len: 0 };
+    write!(counter, "{}", value)?;
+    Ok(counter.len)
+}
+
+/// Returns the widest [`char`] length of the items.
+pub(crate) fn max_char_len<I>(iter: I) -> result::Result<usize, Error>
+where
+    I: Iterator,
+    I::Item: Display,
+{
+    let mut max = 0;
+    for item in iter {
+        max = max.max(char_len(&item)?);
+    }
+    Ok(max)
+}
+
+/// Returns the number of bytes written by `value`.
+pub(crate) fn byte_len<T: Display + ?Sized>(
+    value: &T,
+) -> result::Result<usize, Error> {
+    struct Counter {
+        len: usize,
+    }
+
+    impl Write for Counter {
+        fn write_str(&mut self, s: &str) -> Result {
+            self.len += s.len();
+            Ok(())
+        }
+    }
+
+    let mut counter = Counter { len: 0 };
+    write!(counter, "{}", value)?;
+    Ok(counter.len)
+}
+
+/// Returns whether `value` writes nothing.
+///
+/// Formatting stops at the first non-empty write.
+pub(crate) fn is_empty<T: Display + ?Sized>(
+    value: &T,
+) -> result::Result<bool, Error> {
+    struct Probe {
+        is_empty: bool,
+    }
+
+    impl Write for Probe {
+        fn write_str(&mut self, s: &str) -> Result {
+            if s.is_empty() {
+                Ok(())
+            } else {
+                self.is_empty = false;
+                Err(Error)
+            }
+        }
+    }
+
+    let mut probe = Probe { is_empty: true };
+    let result = write!(probe, "{}", value);
+
+    if probe.is_empty {
+        result.map(|_| true)
+    } else {
+        Ok(false)
+    }
+}
+
+impl<T: Display> Display for WithCount<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        struct Writer<'a, 'b> {
+            f: &'a mut Formatter<'b>,
+            words: bool,
+            in_word: bool,
+            count: usize,
+        }
+
+        impl Write for Writer<'_, '_> {
+            fn write_str(&mut self, s: &str) -> Result {
+                if self.words {
+                    for c in s.chars() {
+                        let is_space = c.is_whitespace();
+                        if !is_space && !self.in_word {
+                            self.count += 1;
+                        }
+                        self.in_word = !is_space;
+                    }
+                } else {
+                    self.count += s.chars().count();
+                }
+
+                self.f.write_str(s)
+            }
+        }
+
+        let mut writer =
+            Writer { f, words: self.words, in_word: false, count: 0 };
+        write!(writer, "{}", self.value)?;
+
+        let count = writer.count;
+        let unit = if self.words { "word" } else { "char" };
+        let plural = if count == 1 { "" } else { "s" };
+
+        write!(f, " ({} {}{})", count, unit, plural)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bold, colored, green};
+
+    #[test]
+    fn visible() {
+        assert_eq!(visible_width(""), Ok(0));
+        assert_eq!(visible_width("héllo"), Ok(5));
+        assert_eq!(visible_width(&colored("héllo", 31)), Ok(5));
+        assert_eq!(visible_width(&bold(green(12345))), Ok(5));
+        assert_eq!(visible_width("\x1b[1;31m\x1b[0m"), Ok(0));
+        assert_eq!(
+            visible_width(&format_args!("{}{}", "a\x1b[3", "1mb")),
+            Ok(2),
+        );
+    }
+
+    #[test]
+    fn word_count() {
+        let cases = [
+            ("", "0 words"),
+            ("   ", "0 words"),
+            ("one", "1 word"),
+            ("  one\n", "1 word"),
+            ("one two", "2 words"),
+            ("one\ttwo\n\nthree  four ", "4 words"),
+        ];
+
+        for (value, count) in cases {
+            assert_eq!(
+                with_word_count(value).to_string(),
+                format!("{} ({})", value, count),
+            );
+        }
+
+        // Words split across writes.
+        let value = crate::concat_tuple(("one tw", 'o', " thr", "ee"));
+        assert_eq!(
+            with_word_count(value).to_string(),
+            "one two three (3 words)",
+        );
+        let value = crate::concat_tuple(("one", ' ', "two"));
+        assert_eq!(with_word_count(value).to_string(), "one two (2 words)");
+    }
+
+    #[test]
+    fn char_count() {
+        assert_eq!(with_char_count("").to_string(), " (0 chars)");
+        assert_eq!(with_char_count('é').to_string(), "é (1 char)");
+        assert_eq!(
+            with_char_count(crate::concat_tuple(("a b", 42))).to_string(),
+            "a b42 (5 chars)",
+        );
+    }
+}
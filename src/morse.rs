@@ -0,0 +1,174 @@
+use core::fmt::*;
+
+pub(crate) mod types {
+    #[allow(unused)]
+    use super::*;
+
+    /// See [`morse()`].
+    #[derive(Clone, Copy)]
+    pub struct Morse<T, L, W> {
+        pub(super) value: T,
+        pub(super) dot: char,
+        pub(super) dash: char,
+        pub(super) letter_sep: L,
+        pub(super) word_sep: W,
+    }
+}
+
+use types::*;
+
+/// Writes ASCII letters and digits in [Morse code](https://en.wikipedia.org/wiki/Morse_code).
+///
+/// Each letter is written as `dot` and `dash` symbols, with `letter_sep`
+/// between letters and `word_sep` between words. Words are separated by
+/// whitespace. Letters are case-insensitive, and other characters are skipped.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::morse("SOS", '.', '-', ' ', " / ");
+/// assert_eq!(value.to_string(), "... --- ...");
+///
+/// let value = fmty::morse("hi there", '.', '-', ' ', " / ");
+/// assert_eq!(value.to_string(), ".... .. / - .... . .-. .");
+/// ```
+pub fn morse<T, L, W>(
+    value: T,
+    dot: char,
+    dash: char,
+    letter_sep: L,
+    word_sep: W,
+) -> Morse<T, L, W> {
+    Morse { value, dot, dash, letter_sep, word_sep }
+}
+
+/// Returns the Morse code of an ASCII letter or digit as `.` and `-`.
+fn code(c: char) -> Option<&'static str> {
+    const LETTERS: [&str; 26] = [
+        ".-", "-...", "-.-.", "-..", ".", "..-.", "--.", "....", "..", ".---",
+        "-.-", ".-..", "--", "-.", "---", ".--.", "--.-", ".-.", "...", "-",
+        "..-", "...-", ".--", "-..-", "-.--", "--..",
+    ];
+
+    const DIGITS: [&str; 10] = [
+        "-----", ".----", "..---", "...--", "....-", ".....", "-....", "--...",
+        "---..", "----.",
+    ];
+
+    match c.to_ascii_uppercase() {
+        c @ 'A'..='Z' => Some(LETTERS[c as usize - 'A' as usize]),
+        c @ '0'..='9' => Some(DIGITS[c as usize - '0' as usize]),
+        _ => None,
+    }
+}
+
+impl<T: Display, L: Display, W: Display> Display for Morse<T, L, W> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        /// What to write before the next letter.
+        #[derive(Clone, Copy)]
+        enum Sep {
+            None,
+            Letter,
+            Word,
+        }
+
+        struct Writer<'a, 'b, L, W> {
+            f: &'a mut Formatter<'b>,
+            symbols: [char; 2],
+            letter_sep: &'a L,
+            word_sep: &'a W,
+            sep: Sep,
+        }
+
+        impl<L: Display, W: Display> Write for Writer<'_, '_, L, W> {
+            fn write_str(&mut self, s: &str) -> Result {
+                for c in s.chars() {
+                    if c.is_whitespace() {
+                        if let Sep::Letter = self.sep {
+                            self.sep = Sep::Word;
+                        }
+                        continue;
+                    }
+
+                    let code = match code(c) {
+                        Some(code) => code,
+                        None => continue,
+                    };
+
+                    match self.sep {
+                        Sep::None => {}
+                        Sep::Letter => write!(self.f, "{}", self.letter_sep)?,
+                        Sep::Word => write!(self.f, "{}", self.word_sep)?,
+                    }
+                    self.sep = Sep::Letter;
+
+                    let [dot, dash] = self.symbols;
+                    for symbol in code.bytes() {
+                        let c = if symbol == b'.' { dot } else { dash };
+                        self.f.write_char(c)?;
+                    }
+                }
+                Ok(())
+            }
+        }
+
+        let mut writer = Writer {
+            f,
+            symbols: [self.dot, self.dash],
+            letter_sep: &self.letter_sep,
+            word_sep: &self.word_sep,
+            sep: Sep::None,
+        };
+        write!(writer, "{}", self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sos() {
+        assert_eq!(
+            morse("SOS", '.', '-', ' ', " / ").to_string(),
+            "... --- ..."
+        );
+        assert_eq!(morse("sos", '·', '−', "", "").to_string(), "···−−−···");
+    }
+
+    #[test]
+    fn words() {
+        let value = morse("  CQ  DX 73! ", '.', '-', ' ', " / ");
+        assert_eq!(value.to_string(), "-.-. --.- / -.. -..- / --... ...--");
+
+        // Unknown characters are skipped without separators.
+        let value = morse("a-b ? c", '.', '-', '|', '/');
+        assert_eq!(value.to_string(), ".-|-.../-.-.");
+
+        assert_eq!(morse("", '.', '-', ' ', '/').to_string(), "");
+        assert_eq!(morse(" ?! ", '.', '-', ' ', '/').to_string(), "");
+    }
+
+    #[test]
+    fn all_codes() {
+        let value =
+            morse("abcdefghijklmnopqrstuvwxyz0123456789", '.', '-', ' ', "");
+        assert_eq!(
+            value.to_string(),
+            core::concat!(
+                ".- -... -.-. -.. . ..-. --. .... .. .--- -.- .-.. -- -. --- ",
+                ".--. --.- .-. ... - ..- ...- .-- -..- -.-- --.. ",
+                "----- .---- ..--- ...-- ....- ..... -.... --... ---.. ----.",
+            ),
+        );
+    }
+
+    #[test]
+    fn parts() {
+        let value = crate::concat_tuple(("S", 'O', " ", "", "S"));
+        assert_eq!(
+            morse(value, '.', '-', ' ', " / ").to_string(),
+            "... --- / ..."
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use core::fmt::*;
+
+pub(crate) mod types {
+    #[allow(unused)]
+    use super::*;
+
+    /// See [`ipv4()`].
+    #[derive(Clone, Copy)]
+    pub struct Ipv4 {
+        pub(super) octets: [u8; 4],
+    }
+
+    /// See [`ipv6()`].
+    #[derive(Clone, Copy)]
+    pub struct Ipv6 {
+        pub(super) segments: [u16; 8],
+    }
+}
+
+use types::*;
+
+/// Writes an IPv4 address in dotted-decimal notation.
+///
+/// This is useful for formatting addresses without
+/// [`std::net`](https://doc.rust-lang.org/std/net/index.html).
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::ipv4([192, 168, 0, 1]);
+/// assert_eq!(value.to_string(), "192.168.0.1");
+/// ```
+pub fn ipv4(octets: [u8; 4]) -> Ipv4 {
+    Ipv4 { octets }
+}
+
+/// Writes an IPv6 address in the canonical form of [RFC 5952](https://www.rfc-editor.org/rfc/rfc5952).
+///
+/// Segments are written in lowercase hexadecimal without leading zeros. The
+/// longest run of two or more zero segments is replaced with `::`, preferring
+/// the first run if there is a tie.
+///
+/// Unlike [`std::net::Ipv6Addr`](https://doc.rust-lang.org/std/net/struct.Ipv6Addr.html),
+/// IPv4-mapped addresses are not written with a dotted-decimal suffix.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::ipv6([0x2001, 0xdb8, 0, 0, 0, 0, 0, 1]);
+/// assert_eq!(value.to_string(), "2001:db8::1");
+/// ```
+pub fn ipv6(segments: [u16; 8]) -> Ipv6 {
+    Ipv6 { segments }
+}
+
+impl Display for Ipv4 {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let [a, b, c, d] = self.octets;
+        write!(f, "{}.{}.{}.{}", a, b, c, d)
+    }
+}
+
+impl Display for Ipv6 {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let segments = &self.segments;
+
+        // Find the longest run of zeros.
+        let (mut zeros_start, mut zeros_len) = (0, 0);
+        let mut run_start = 0;
+
+        for (i, &segment) in segments.iter().enumerate() {
+            if segment != 0 {
+                run_start = i + 1;
+            } else if i + 1 - run_start > zeros_len {
+                zeros_start = run_start;
+                zeros_len = i + 1 - run_start;
+            }
+        }
+
+        /// Writes segments separated by `:`.
+        fn write_segments(f: &mut Formatter, segments: &[u16]) -> Result {
+            for (i, segment) in segments.iter().enumerate() {
+                if i != 0 {
+                    f.write_char(':')?;
+                }
+                write!(f, "{:x}", segment)?;
+            }
+            Ok(())
+        }
+
+        if zeros_len < 2 {
+            return write_segments(f, segments);
+        }
+
+        write_segments(f, &segments[..zeros_start])?;
+        f.write_str("::")?;
+        write_segments(f, &segments[zeros_start + zeros_len..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv6Addr;
+
+    use test_strategy::proptest;
+
+    use super::*;
+
+    #[test]
+    fn v4() {
+        assert_eq!(ipv4([0, 0, 0, 0]).to_string(), "0.0.0.0");
+        assert_eq!(ipv4([127, 0, 0, 1]).to_string(), "127.0.0.1");
+        assert_eq!(ipv4([255; 4]).to_string(), "255.255.255.255");
+    }
+
+    #[test]
+    fn v6() {
+        let cases = [
+            ([0, 0, 0, 0, 0, 0, 0, 0], "::"),
+            ([0, 0, 0, 0, 0, 0, 0, 1], "::1"),
+            ([1, 0, 0, 0, 0, 0, 0, 0], "1::"),
+            ([0x2001, 0xdb8, 0, 0, 0, 0, 0, 1], "2001:db8::1"),
+            ([0x2001, 0xdb8, 0, 1, 0, 0, 0, 1], "2001:db8:0:1::1"),
+            ([0x2001, 0xdb8, 0, 0, 1, 0, 0, 1], "2001:db8::1:0:0:1"),
+            ([0x2001, 0xdb8, 0, 1, 1, 1, 1, 1], "2001:db8:0:1:1:1:1:1"),
+            (
+                [0x2001, 0xdb8, 0x85a3, 0x8d3, 0x1319, 0x8a2e, 0x370, 0x7348],
+                "2001:db8:85a3:8d3:1319:8a2e:370:7348",
+            ),
+        ];
+
+        for (segments, expected) in cases {
+            assert_eq!(ipv6(segments).to_string(), expected);
+        }
+    }
+
+    /// Tests against `std` for addresses that are not IPv4-compatible.
+    #[proptest]
+    fn v6_std(segments: [u16; 8]) {
+        let std = Ipv6Addr::from(segments);
+
+        if std.to_ipv4().is_none() {
+            assert_eq!(ipv6(segments).to_string(), std.to_string());
+        }
+    }
+}
@@ -0,0 +1,341 @@
+use core::fmt::*;
+
+use crate::map_chars::MapCharsWriter;
+
+pub(crate) mod types {
+    #[allow(unused)]
+    use super::*;
+
+    /// See [`roman()`], [`roman_lower()`].
+    #[derive(Clone, Copy)]
+    pub struct Roman {
+        pub(super) n: u32,
+        pub(super) lowercase: bool,
+    }
+
+    /// See [`bits()`], [`bits_grouped()`].
+    #[derive(Clone, Copy)]
+    pub struct Bits<'a, S = &'static str> {
+        pub(super) bits: &'a [bool],
+        pub(super) group: usize,
+        pub(super) sep: S,
+    }
+
+    /// See [`percent()`], [`percent_int()`].
+    #[derive(Clone, Copy)]
+    pub struct Percent {
+        pub(super) fraction: f64,
+        pub(super) decimals: usize,
+    }
+
+    /// See [`fraction()`], [`fraction_slashed()`].
+    #[derive(Clone, Copy)]
+    pub struct Fraction {
+        pub(super) num: u32,
+        pub(super) den: u32,
+        pub(super) slashed: bool,
+    }
+}
+
+use types::*;
+
+/// Writes a number in uppercase [Roman numerals](https://en.wikipedia.org/wiki/Roman_numerals).
+///
+/// Roman numerals have no zero, so `0` writes nothing. Standard numerals only
+/// reach 3999, so larger numbers are written in decimal.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(fmty::roman(2024).to_string(), "MMXXIV");
+/// assert_eq!(fmty::roman(0).to_string(), "");
+/// assert_eq!(fmty::roman(4000).to_string(), "4000");
+/// ```
+pub fn roman(n: u32) -> Roman {
+    Roman { n, lowercase: false }
+}
+
+/// Writes a number in lowercase [Roman numerals](https://en.wikipedia.org/wiki/Roman_numerals).
+///
+/// See [`roman()`] for details.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(fmty::roman_lower(2024).to_string(), "mmxxiv");
+/// ```
+pub fn roman_lower(n: u32) -> Roman {
+    Roman { n, lowercase: true }
+}
+
+/// Writes each [`bool`] as `1` or `0`.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::bits(&[true, false, true, true]);
+/// assert_eq!(value.to_string(), "1011");
+/// ```
+pub fn bits(bits: &[bool]) -> Bits<'_> {
+    bits_grouped(bits, 0, "")
+}
+
+/// Writes each [`bool`] as `1` or `0`, with a separator after every `group`
+/// bits.
+///
+/// If `group` is 0, no separators are written.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::bits_grouped(&[true, false, true, true, false], 2, "_");
+/// assert_eq!(value.to_string(), "10_11_0");
+/// ```
+pub fn bits_grouped<S>(bits: &[bool], group: usize, sep: S) -> Bits<'_, S> {
+    Bits { bits, group, sep }
+}
+
+/// Writes a fraction as a percentage with `decimals` digits after the decimal
+/// point.
+///
+/// The percentage is rounded like [`f64`] formatting with a precision. If the
+/// fraction is not finite, it is written as `NaN%`, `inf%`, or `-inf%`.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(fmty::percent(0.1234, 1).to_string(), "12.3%");
+/// assert_eq!(fmty::percent(1.0, 2).to_string(), "100.00%");
+/// ```
+pub fn percent(fraction: f64, decimals: usize) -> Percent {
+    Percent { fraction, decimals }
+}
+
+/// Writes a fraction as a whole-number percentage.
+///
+/// This is equivalent to <code>[percent]\(fraction, 0\)</code>.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(fmty::percent_int(0.256).to_string(), "26%");
+/// ```
+pub fn percent_int(fraction: f64) -> Percent {
+    percent(fraction, 0)
+}
+
+/// Writes a fraction as a single Unicode glyph like `½` if one exists, or as
+/// `num/den` otherwise.
+///
+/// Glyphs exist for halves, thirds, quarters, fifths, sixths, sevenths,
+/// eighths, ninths, and tenths with a numerator of 1, as well as `⅔`, `¾`,
+/// `⅖`, `⅗`, `⅘`, `⅚`, `⅜`, `⅝`, `⅞`, and `↉`. The fraction is not reduced,
+/// so `2/4` is not written as `½`.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(fmty::fraction(1, 2).to_string(), "½");
+/// assert_eq!(fmty::fraction(7, 13).to_string(), "7/13");
+/// ```
+pub fn fraction(num: u32, den: u32) -> Fraction {
+    Fraction { num, den, slashed: false }
+}
+
+/// Writes a fraction with a superscript numerator and subscript denominator
+/// around the fraction slash `⁄`.
+///
+/// Unlike [`fraction()`], this can write any fraction in the same style.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(fmty::fraction_slashed(7, 13).to_string(), "⁷⁄₁₃");
+/// ```
+pub fn fraction_slashed(num: u32, den: u32) -> Fraction {
+    Fraction { num, den, slashed: true }
+}
+
+impl Display for Roman {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        const NUMERALS: [(u32, &str, &str); 13] = [
+            (1000, "M", "m"),
+            (900, "CM", "cm"),
+            (500, "D", "d"),
+            (400, "CD", "cd"),
+            (100, "C", "c"),
+            (90, "XC", "xc"),
+            (50, "L", "l"),
+            (40, "XL", "xl"),
+            (10, "X", "x"),
+            (9, "IX", "ix"),
+            (5, "V", "v"),
+            (4, "IV", "iv"),
+            (1, "I", "i"),
+        ];
+
+        if self.n > 3999 {
+            return write!(f, "{}", self.n);
+        }
+
+        let mut n = self.n;
+
+        for &(value, upper, lower) in &NUMERALS {
+            let numeral = if self.lowercase { lower } else { upper };
+
+            while n >= value {
+                f.write_str(numeral)?;
+                n -= value;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<S: Display> Display for Bits<'_, S> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        for (i, &bit) in self.bits.iter().enumerate() {
+            if i != 0 && self.group != 0 && i % self.group == 0 {
+                write!(f, "{}", self.sep)?;
+            }
+            f.write_char(if bit { '1' } else { '0' })?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Percent {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{:.*}%", self.decimals, self.fraction * 100.0)
+    }
+}
+
+impl Display for Fraction {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        if self.slashed {
+            const SUPERSCRIPTS: [char; 10] =
+                ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+            const SUBSCRIPTS: [char; 10] =
+                ['₀', '₁', '₂', '₃', '₄', '₅', '₆', '₇', '₈', '₉'];
+
+            let sup = |c: char| SUPERSCRIPTS[c as usize - '0' as usize];
+            let sub = |c: char| SUBSCRIPTS[c as usize - '0' as usize];
+
+            write!(MapCharsWriter { f, map: sup }, "{}", self.num)?;
+            f.write_char('⁄')?;
+            return write!(MapCharsWriter { f, map: sub }, "{}", self.den);
+        }
+
+        let glyph = match (self.num, self.den) {
+            (1, 2) => '½',
+            (1, 3) => '⅓',
+            (2, 3) => '⅔',
+            (1, 4) => '¼',
+            (3, 4) => '¾',
+            (1, 5) => '⅕',
+            (2, 5) => '⅖',
+            (3, 5) => '⅗',
+            (4, 5) => '⅘',
+            (1, 6) => '⅙',
+            (5, 6) => '⅚',
+            (1, 7) => '⅐',
+            (1, 8) => '⅛',
+            (3, 8) => '⅜',
+            (5, 8) => '⅝',
+            (7, 8) => '⅞',
+            (1, 9) => '⅑',
+            (1, 10) => '⅒',
+            (0, 3) => '↉',
+            (num, den) => return write!(f, "{}/{}", num, den),
+        };
+        f.write_char(glyph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roman_numerals() {
+        let cases = [
+            (0, ""),
+            (1, "I"),
+            (4, "IV"),
+            (9, "IX"),
+            (14, "XIV"),
+            (40, "XL"),
+            (90, "XC"),
+            (400, "CD"),
+            (1994, "MCMXCIV"),
+            (3999, "MMMCMXCIX"),
+            (4000, "4000"),
+            (u32::MAX, "4294967295"),
+        ];
+
+        for (n, expected) in cases {
+            assert_eq!(roman(n).to_string(), expected);
+            assert_eq!(roman_lower(n).to_string(), expected.to_lowercase());
+        }
+    }
+
+    #[test]
+    fn bit_string() {
+        let value = [true, false, true, false, false, true, false, true];
+
+        assert_eq!(bits(&value).to_string(), "10100101");
+        assert_eq!(bits(&[]).to_string(), "");
+
+        assert_eq!(bits_grouped(&value, 4, ' ').to_string(), "1010 0101");
+        assert_eq!(bits_grouped(&value, 3, ' ').to_string(), "101 001 01");
+        assert_eq!(bits_grouped(&value, 8, ' ').to_string(), "10100101");
+        assert_eq!(bits_grouped(&value, 0, ' ').to_string(), "10100101");
+    }
+
+    #[test]
+    fn percentages() {
+        assert_eq!(percent(0.0, 0).to_string(), "0%");
+        assert_eq!(percent(0.5, 0).to_string(), "50%");
+        assert_eq!(percent(0.1234, 0).to_string(), "12%");
+        assert_eq!(percent(0.1234, 2).to_string(), "12.34%");
+        assert_eq!(percent(0.12345678, 3).to_string(), "12.346%");
+        assert_eq!(percent(-0.25, 1).to_string(), "-25.0%");
+        assert_eq!(percent(2.5, 0).to_string(), "250%");
+
+        assert_eq!(percent_int(0.004).to_string(), "0%");
+        assert_eq!(percent_int(0.006).to_string(), "1%");
+        assert_eq!(percent_int(0.996).to_string(), "100%");
+    }
+
+    #[test]
+    fn percent_non_finite() {
+        assert_eq!(percent(f64::NAN, 1).to_string(), "NaN%");
+        assert_eq!(percent(f64::INFINITY, 1).to_string(), "inf%");
+        assert_eq!(percent(f64::NEG_INFINITY, 1).to_string(), "-inf%");
+    }
+
+    #[test]
+    fn fractions() {
+        let cases = [
+            (1, 2, "½"),
+            (5, 8, "⅝"),
+            (1, 10, "⅒"),
+            (0, 3, "↉"),
+            (2, 4, "2/4"),
+            (7, 13, "7/13"),
+            (3, 0, "3/0"),
+        ];
+
+        for (num, den, expected) in cases {
+            assert_eq!(fraction(num, den).to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn fractions_slashed() {
+        assert_eq!(fraction_slashed(1, 2).to_string(), "¹⁄₂");
+        assert_eq!(fraction_slashed(5, 8).to_string(), "⁵⁄₈");
+        assert_eq!(fraction_slashed(7, 13).to_string(), "⁷⁄₁₃");
+        assert_eq!(fraction_slashed(0, 1234567890).to_string(), "⁰⁄₁₂₃₄₅₆₇₈₉₀");
+    }
+}
@@ -0,0 +1,533 @@
+use core::fmt::*;
+
+use crate::{
+    measure::{char_len, max_char_len, visible_width},
+    truncate::write_skipping_chars,
+};
+
+pub(crate) mod types {
+    #[allow(unused)]
+    use super::*;
+
+    /// Horizontal alignment of a value within a column.
+    ///
+    /// See [`table()`](crate::table()).
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum Align {
+        /// Padding is written after the value.
+        Left,
+
+        /// Padding is written before the value.
+        Right,
+
+        /// Padding is split around the value, with the extra space after it.
+        Center,
+    }
+
+    /// See [`align_right_all()`].
+    #[derive(Clone, Copy)]
+    pub struct AlignRightAll<I, S> {
+        pub(super) iter: I,
+        pub(super) sep: S,
+    }
+
+    /// See [`pad_left_visible()`], [`pad_right_visible()`],
+    /// [`center_visible()`].
+    #[derive(Clone, Copy)]
+    pub struct PadVisible<T> {
+        pub(super) value: T,
+        pub(super) width: usize,
+        pub(super) align: Align,
+    }
+
+    /// See [`leader()`].
+    #[derive(Clone, Copy)]
+    pub struct Leader<L, R> {
+        pub(super) left: L,
+        pub(super) right: R,
+        pub(super) fill: char,
+        pub(super) width: usize,
+    }
+
+    /// See [`annotate()`].
+    #[derive(Clone, Copy)]
+    pub struct Annotate<T, N, C> {
+        pub(super) value: T,
+        pub(super) note: N,
+        pub(super) column: usize,
+        pub(super) comment: C,
+    }
+
+    /// See [`pad_left_pattern()`].
+    #[derive(Clone, Copy)]
+    pub struct PadLeftPattern<'a, T> {
+        pub(super) value: T,
+        pub(super) width: usize,
+        pub(super) pattern: &'a str,
+    }
+
+    /// See [`fit_right()`].
+    #[derive(Clone, Copy)]
+    pub struct FitRight<T, E> {
+        pub(super) value: T,
+        pub(super) width: usize,
+        pub(super) ellipsis: E,
+    }
+
+    /// See [`spread()`].
+    #[derive(Clone, Copy)]
+    pub struct Spread<L, R> {
+        pub(super) left: L,
+        pub(super) right: R,
+    }
+}
+
+impl Align {
+    /// Splits `fill` into padding before and after a value.
+    pub(crate) fn split(self, fill: usize) -> (usize, usize) {
+        match self {
+            Self::Left => (0, fill),
+            Self::Right => (fill, 0),
+            Self::Center => (fill / 2, fill - fill / 2),
+        }
+    }
+}
+
+use types::*;
+
+/// Right-aligns [`Iterator`] items to the widest item, with a separator between
+/// each.
+///
+/// Width is measured in [`char`]s. Because the width of every item must be
+/// known before writing the first, each item is formatted twice.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::align_right_all([1, 20, 300], "\n");
+/// assert_eq!(value.to_string(), "  1\n 20\n300");
+///
+/// let value = fmty::align_right_all([5, 42, 100], " | ");
+/// assert_eq!(value.to_string(), "  5 |  42 | 100");
+/// ```
+#[doc(alias = "join_aligned")]
+pub fn align_right_all<I, S>(iter: I, sep: S) -> AlignRightAll<I::IntoIter, S>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+{
+    AlignRightAll { iter: iter.into_iter(), sep }
+}
+
+/// Pads a value with spaces on the left to `width`, ignoring ANSI escape
+/// sequences.
+///
+/// Width is measured with [`visible_width()`], so styled text lines up with
+/// plain text. Because the width must be known before writing, the value is
+/// formatted twice.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::pad_left_visible(fmty::red(42), 5);
+/// assert_eq!(value.to_string(), "   \x1b[31m42\x1b[0m");
+/// ```
+pub fn pad_left_visible<T>(value: T, width: usize) -> PadVisible<T> {
+    PadVisible { value, width, align: Align::Right }
+}
+
+/// Pads a value with spaces on the right to `width`, ignoring ANSI escape
+/// sequences.
+///
+/// See [`pad_left_visible()`] for details.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::pad_right_visible(fmty::red(42), 5);
+/// assert_eq!(value.to_string(), "\x1b[31m42\x1b[0m   ");
+/// ```
+pub fn pad_right_visible<T>(value: T, width: usize) -> PadVisible<T> {
+    PadVisible { value, width, align: Align::Left }
+}
+
+/// Pads a value with spaces on both sides to `width`, ignoring ANSI escape
+/// sequences.
+///
+/// If the padding is uneven, the extra space goes on the right. See
+/// [`pad_left_visible()`] for details.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::center_visible(fmty::red(42), 5);
+/// assert_eq!(value.to_string(), " \x1b[31m42\x1b[0m  ");
+/// ```
+pub fn center_visible<T>(value: T, width: usize) -> PadVisible<T> {
+    PadVisible { value, width, align: Align::Center }
+}
+
+/// Writes `left` and `right` at either end of `width`, with `fill` between
+/// them, like a table of contents.
+///
+/// If `left` and `right` do not fit within `width`, a single `fill` is written
+/// between them.
+///
+/// Width is measured in [`char`]s. Because the widths must be known before
+/// writing, `left` and `right` are each formatted twice.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::leader("Chapter 1", 12, '.', 20);
+/// assert_eq!(value.to_string(), "Chapter 1.........12");
+/// ```
+pub fn leader<L, R>(
+    left: L,
+    right: R,
+    fill: char,
+    width: usize,
+) -> Leader<L, R> {
+    Leader { left, right, fill, width }
+}
+
+/// Writes `left` and `right` with spaces between them to fill the
+/// [`Formatter::width()`].
+///
+/// This pushes `right` to the end of the line, like a status bar. If `left`
+/// and `right` do not fit within the width, or no width is given, a single
+/// space is written between them.
+///
+/// This is equivalent to <code>[leader]\(left, right, ' ', width\)</code> with
+/// the width taken from the format string.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::spread("main.rs", "12:4");
+/// assert_eq!(format!("{:16}", value), "main.rs     12:4");
+/// assert_eq!(format!("{}", value), "main.rs 12:4");
+/// ```
+pub fn spread<L, R>(left: L, right: R) -> Spread<L, R> {
+    Spread { left, right }
+}
+
+/// Writes a value followed by `comment` and `note` starting at `column`, like
+/// an end-of-line comment.
+///
+/// If the value reaches `column`, a single space is written between it and
+/// `comment`.
+///
+/// Width is measured in [`char`]s. Because the width must be known before
+/// writing, the value is formatted twice.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::annotate("let x = 1;", "the answer", 16, "// ");
+/// assert_eq!(value.to_string(), "let x = 1;      // the answer");
+/// ```
+pub fn annotate<T, N, C>(
+    value: T,
+    note: N,
+    column: usize,
+    comment: C,
+) -> Annotate<T, N, C> {
+    Annotate { value, note, column, comment }
+}
+
+/// Pads a value on the left to `width` with repetitions of `pattern`.
+///
+/// The last repetition is cut short if it does not fit. If `pattern` is empty,
+/// no padding is written.
+///
+/// Width is measured in [`char`]s. Because the width must be known before
+/// writing, the value is formatted twice.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::pad_left_pattern(42, 7, ". ");
+/// assert_eq!(value.to_string(), ". . .42");
+/// ```
+pub fn pad_left_pattern<T>(
+    value: T,
+    width: usize,
+    pattern: &str,
+) -> PadLeftPattern<'_, T> {
+    PadLeftPattern { value, width, pattern }
+}
+
+/// Fits a value to exactly `width` [`char`]s, keeping its end.
+///
+/// Shorter values are padded with spaces on the left. Longer values keep their
+/// last [`char`]s, preceded by `ellipsis`, which counts toward `width`. The
+/// ellipsis is written in full even if it is wider than `width`. This is useful
+/// for showing the end of a long path.
+///
+/// Because the widths must be known before writing, the value and ellipsis are
+/// each formatted twice.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::fit_right("/a/long/path", 8, "…");
+/// assert_eq!(value.to_string(), "…ng/path");
+///
+/// let value = fmty::fit_right("/a", 8, "…");
+/// assert_eq!(value.to_string(), "      /a");
+/// ```
+pub fn fit_right<T, E>(value: T, width: usize, ellipsis: E) -> FitRight<T, E> {
+    FitRight { value, width, ellipsis }
+}
+
+/// Writes `c` `n` times.
+pub(crate) fn write_fill(f: &mut impl Write, c: char, n: usize) -> Result {
+    for _ in 0..n {
+        f.write_char(c)?;
+    }
+    Ok(())
+}
+
+impl<I, S> Display for AlignRightAll<I, S>
+where
+    I: Iterator + Clone,
+    I::Item: Display,
+    S: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let width = max_char_len(self.iter.clone())?;
+
+        for (i, item) in self.iter.clone().enumerate() {
+            if i != 0 {
+                write!(f, "{}", self.sep)?;
+            }
+            write_fill(f, ' ', width - char_len(&item)?)?;
+            write!(f, "{}", item)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Display> Display for PadVisible<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let fill = self.width.saturating_sub(visible_width(&self.value)?);
+
+        let (left, right) = self.align.split(fill);
+
+        write_fill(f, ' ', left)?;
+        write!(f, "{}", self.value)?;
+        write_fill(f, ' ', right)
+    }
+}
+
+impl<L: Display, R: Display> Display for Leader<L, R> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let len = char_len(&self.left)? + char_len(&self.right)?;
+        let fill = self.width.saturating_sub(len).max(1);
+
+        write!(f, "{}", self.left)?;
+        write_fill(f, self.fill, fill)?;
+        write!(f, "{}", self.right)
+    }
+}
+
+impl<L: Display, R: Display> Display for Spread<L, R> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let width = f.width().unwrap_or(0);
+        write!(f, "{}", leader(&self.left, &self.right, ' ', width))
+    }
+}
+
+impl<T, N, C> Display for Annotate<T, N, C>
+where
+    T: Display,
+    N: Display,
+    C: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let fill = self.column.saturating_sub(char_len(&self.value)?).max(1);
+
+        write!(f, "{}", self.value)?;
+        write_fill(f, ' ', fill)?;
+        write!(f, "{}{}", self.comment, self.note)
+    }
+}
+
+impl<T: Display> Display for PadLeftPattern<'_, T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let fill = self.width.saturating_sub(char_len(&self.value)?);
+
+        for c in self.pattern.chars().cycle().take(fill) {
+            f.write_char(c)?;
+        }
+        write!(f, "{}", self.value)
+    }
+}
+
+impl<T: Display, E: Display> Display for FitRight<T, E> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let len = char_len(&self.value)?;
+
+        if len <= self.width {
+            write_fill(f, ' ', self.width - len)?;
+            return write!(f, "{}", self.value);
+        }
+
+        let keep = self.width.saturating_sub(char_len(&self.ellipsis)?);
+
+        write!(f, "{}", self.ellipsis)?;
+        write_skipping_chars(f, &self.value, len - keep)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn align_right() {
+        let value = align_right_all([7, 42, 1000, -3], "\n");
+        assert_eq!(value.to_string(), "   7\n  42\n1000\n  -3");
+
+        let value = align_right_all(["a", "bbb", "cc"], "|");
+        assert_eq!(value.to_string(), "  a|bbb| cc");
+
+        let value = align_right_all([""; 0], "\n");
+        assert_eq!(value.to_string(), "");
+    }
+
+    #[test]
+    fn pad_visible() {
+        use crate::{bold, green, strip_ansi};
+
+        let value = green("ok");
+        assert_eq!(
+            pad_left_visible(value, 4).to_string(),
+            "  \x1b[32mok\x1b[0m"
+        );
+        assert_eq!(
+            pad_right_visible(value, 4).to_string(),
+            "\x1b[32mok\x1b[0m  "
+        );
+        assert_eq!(center_visible(value, 4).to_string(), " \x1b[32mok\x1b[0m ");
+
+        // Already wide enough.
+        assert_eq!(pad_left_visible("abc", 2).to_string(), "abc");
+        assert_eq!(center_visible("", 0).to_string(), "");
+
+        // Colored and plain cells line up.
+        let cells = [
+            pad_right_visible(bold(green("pass")), 6).to_string(),
+            pad_right_visible("fail", 6).to_string(),
+        ];
+        for cell in &cells {
+            assert_eq!(strip_ansi(cell).to_string().len(), 6);
+        }
+    }
+
+    #[test]
+    fn leaders() {
+        assert_eq!(leader("Intro", 1, '.', 10).to_string(), "Intro....1");
+        assert_eq!(leader("Index", 102, ' ', 10).to_string(), "Index  102");
+        assert_eq!(leader("", "", '-', 3).to_string(), "---");
+
+        // Too wide to fit.
+        assert_eq!(leader("Intro", 1, '.', 6).to_string(), "Intro.1");
+        assert_eq!(
+            leader("Introduction", 1, '.', 4).to_string(),
+            "Introduction.1"
+        );
+
+        let toc = [("Début", 1), ("Fin", 42)]
+            .iter()
+            .map(|&(title, page)| leader(title, page, '·', 12).to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(toc, ["Début······1", "Fin·······42"]);
+    }
+
+    #[test]
+    fn annotations() {
+        assert_eq!(annotate("ab", "note", 5, "# ").to_string(), "ab   # note");
+        assert_eq!(annotate("", "note", 2, "# ").to_string(), "  # note");
+
+        // Long values.
+        assert_eq!(
+            annotate("abcde", "note", 5, "# ").to_string(),
+            "abcde # note"
+        );
+        assert_eq!(
+            annotate("abcdefg", 1, 5, "// ").to_string(),
+            "abcdefg // 1"
+        );
+    }
+
+    #[test]
+    fn pattern() {
+        assert_eq!(pad_left_pattern("ab", 6, "-=").to_string(), "-=-=ab");
+        assert_eq!(pad_left_pattern("ab", 5, "-=").to_string(), "-=-ab");
+
+        // Longer than the gap.
+        assert_eq!(pad_left_pattern("ab", 4, "<->").to_string(), "<-ab");
+
+        // Exact fits.
+        assert_eq!(pad_left_pattern("ab", 2, "-").to_string(), "ab");
+        assert_eq!(pad_left_pattern("abc", 2, "-").to_string(), "abc");
+        assert_eq!(pad_left_pattern("", 3, "xyz").to_string(), "xyz");
+
+        assert_eq!(pad_left_pattern("ab", 4, "").to_string(), "ab");
+        assert_eq!(pad_left_pattern(7, 3, "·0").to_string(), "·07");
+    }
+
+    #[test]
+    fn fit() {
+        let cases = [
+            (0, "…"),
+            (1, "…"),
+            (2, "…f"),
+            (5, "…cdef"),
+            (6, "abcdef"),
+            (8, "  abcdef"),
+        ];
+
+        for (width, expected) in cases {
+            let value = crate::concat_tuple(("abc", 'd', "ef"));
+            assert_eq!(
+                fit_right(value, width, "…").to_string(),
+                expected,
+                "incorrect result for width {width}",
+            );
+        }
+
+        // Ellipsis wider than the field.
+        assert_eq!(fit_right("abcdef", 2, "...").to_string(), "...");
+        assert_eq!(fit_right("abcdef", 4, "...").to_string(), "...f");
+        assert_eq!(fit_right("", 3, "...").to_string(), "   ");
+    }
+
+    #[test]
+    fn align_right_sep() {
+        let value = align_right_all([1, -20, 300, 4000], ",");
+        assert_eq!(value.to_string(), "   1, -20, 300,4000");
+
+        let value = align_right_all([0.5, 10.25], " ");
+        assert_eq!(value.to_string(), "  0.5 10.25");
+
+        assert_eq!(align_right_all([7], ", ").to_string(), "7");
+        assert_eq!(align_right_all([0; 0], ", ").to_string(), "");
+    }
+
+    #[test]
+    fn spread_width() {
+        let value = spread(crate::concat_tuple(("ab", 'c')), "déf");
+
+        assert_eq!(format!("{:10}", value), "abc    déf");
+        assert_eq!(format!("{:7}", value), "abc déf");
+        assert_eq!(format!("{:6}", value), "abc déf");
+        assert_eq!(format!("{:0}", value), "abc déf");
+        assert_eq!(format!("{}", value), "abc déf");
+
+        assert_eq!(format!("{:4}", spread("", "")), "    ");
+        assert_eq!(format!("{:4}", spread("", "x")), "   x");
+    }
+}
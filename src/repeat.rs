@@ -19,6 +19,12 @@ pub(crate) mod types {
         pub(super) f: F,
         pub(super) n: usize,
     }
+
+    /// See [`line_of()`].
+    #[derive(Clone, Copy)]
+    pub struct LineOf {
+        pub(super) c: char,
+    }
 }
 
 use types::*;
@@ -60,6 +66,35 @@ pub fn repeat_with<F>(n: usize, f: F) -> RepeatWith<F> {
     RepeatWith { n, f }
 }
 
+/// Repeats a [`char`] `n` times, such as for drawing a horizontal rule.
+///
+/// This is equivalent to <code>[repeat]\(c, n\)</code>.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::line('─', 5);
+/// assert_eq!(value.to_string(), "─────");
+/// ```
+pub fn line(c: char, n: usize) -> Repeat<char> {
+    repeat(c, n)
+}
+
+/// Repeats a [`char`] as many times as the [width](Formatter::width).
+///
+/// This writes nothing if no width is specified.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::line_of('=');
+/// assert_eq!(format!("{value:4}"), "====");
+/// assert_eq!(format!("{value}"), "");
+/// ```
+pub fn line_of(c: char) -> LineOf {
+    LineOf { c }
+}
+
 impl<T: Debug> Debug for Repeat<T> {
     fn fmt(&self, f: &mut Formatter) -> Result {
         for _ in 0..self.n {
@@ -103,3 +138,35 @@ where
         Ok(())
     }
 }
+
+impl Display for LineOf {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        for _ in 0..f.width().unwrap_or(0) {
+            f.write_char(self.c)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_fixed() {
+        assert_eq!(line('-', 0).to_string(), "");
+        assert_eq!(line('-', 3).to_string(), "---");
+        assert_eq!(line('═', 2).to_string(), "══");
+    }
+
+    #[test]
+    fn line_width() {
+        let value = line_of('-');
+
+        assert_eq!(format!("{value}"), "");
+        assert_eq!(format!("{value:0}"), "");
+        assert_eq!(format!("{value:3}"), "---");
+        assert_eq!(format!("{value:<>3}"), "---");
+        assert_eq!(format!("{value:w$}", w = 6), "------");
+    }
+}
@@ -0,0 +1,65 @@
+use core::fmt::*;
+
+pub(crate) mod types {
+    #[allow(unused)]
+    use super::*;
+
+    /// See [`with_precision()`].
+    #[derive(Clone, Copy)]
+    pub struct WithPrecision<T> {
+        pub(super) value: T,
+        pub(super) precision: usize,
+    }
+}
+
+use types::*;
+
+/// Formats a value with an explicit [precision](Formatter::precision).
+///
+/// This is equivalent to formatting with `{:.precision$}`. It is useful for
+/// values nested within other adapters, where the precision of the outer
+/// format string does not reach the inner value.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::csv_map([1.0, 2.5], |x| fmty::with_precision(x, 2));
+/// assert_eq!(value.to_string(), "1.00, 2.50");
+/// ```
+pub fn with_precision<T>(value: T, precision: usize) -> WithPrecision<T> {
+    WithPrecision { value, precision }
+}
+
+impl<T: Debug> Debug for WithPrecision<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{:.*?}", self.precision, self.value)
+    }
+}
+
+impl<T: Display> Display for WithPrecision<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{:.*}", self.precision, self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn float() {
+        let value = 1.0f64 / 3.0;
+
+        assert_eq!(with_precision(value, 0).to_string(), "0");
+        assert_eq!(with_precision(value, 2).to_string(), "0.33");
+        assert_eq!(with_precision(2.5f64, 10).to_string(), "2.5000000000");
+    }
+
+    #[test]
+    fn nested() {
+        let value = crate::cond(true, 0.125f64);
+
+        assert_eq!(with_precision(value, 1).to_string(), "0.1");
+        assert_eq!(format!("{:?}", with_precision(value, 4)), "0.1250");
+    }
+}
@@ -1,5 +1,7 @@
 use core::fmt::*;
 
+use crate::measure::char_len;
+
 pub(crate) mod types {
     #[allow(unused)]
     use super::*;
@@ -10,6 +12,70 @@ pub(crate) mod types {
         pub(super) value: T,
         pub(super) len: usize,
     }
+
+    /// See [`compact_unless_alternate()`].
+    #[derive(Clone, Copy)]
+    pub struct CompactUnlessAlternate<T> {
+        pub(super) value: T,
+        pub(super) max_len: usize,
+    }
+
+    /// See [`max_width()`].
+    #[derive(Clone, Copy)]
+    pub struct MaxWidth<T> {
+        pub(super) value: T,
+    }
+
+    /// See [`tail_chars()`], [`tail_chars_with()`].
+    #[derive(Clone, Copy)]
+    pub struct TailChars<T, E = &'static str> {
+        pub(super) value: T,
+        pub(super) len: usize,
+        pub(super) ellipsis: E,
+    }
+
+    /// See [`truncate_words()`].
+    #[derive(Clone, Copy)]
+    pub struct TruncateWords<T, E> {
+        pub(super) value: T,
+        pub(super) max_chars: usize,
+        pub(super) ellipsis: E,
+    }
+
+    /// See [`truncate_bytes()`].
+    #[derive(Clone, Copy)]
+    pub struct TruncateBytes<T> {
+        pub(super) value: T,
+        pub(super) max_bytes: usize,
+    }
+
+    /// See [`truncate_with()`].
+    #[derive(Clone, Copy)]
+    pub struct TruncateWith<T, S> {
+        pub(super) value: T,
+        pub(super) len: usize,
+        pub(super) suffix: S,
+    }
+
+    /// See [`truncate_start()`].
+    pub type TruncateStart<T> = TailChars<T>;
+
+    /// See [`truncate_middle()`].
+    #[derive(Clone, Copy)]
+    pub struct TruncateMiddle<T, E> {
+        pub(super) value: T,
+        pub(super) prefix_len: usize,
+        pub(super) suffix_len: usize,
+        pub(super) ellipsis: E,
+    }
+
+    /// See [`truncate_graphemes()`].
+    #[cfg(feature = "grapheme")]
+    #[derive(Clone, Copy)]
+    pub struct TruncateGraphemes<T> {
+        pub(super) value: T,
+        pub(super) len: usize,
+    }
 }
 
 use types::*;
@@ -25,61 +91,516 @@ use types::*;
 /// ```
 /// let value = fmty::truncate_chars(123, 2);
 /// assert_eq!(value.to_string(), "12");
+///
+/// let value = fmty::truncate_chars("hello", 3);
+/// assert_eq!(format!("{:?}", value), "\"he");
 /// ```
 pub fn truncate_chars<T>(value: T, len: usize) -> TruncateChars<T> {
     TruncateChars { value, len }
 }
 
+/// Shortens to `max_len` [`char`]s ending with `…`, unless the alternate flag
+/// (`{:#}`) is used.
+///
+/// This allows one value to be written compactly in some places and in full in
+/// others. The ellipsis counts toward `max_len`, and is only written if the
+/// value is longer than `max_len`.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::compact_unless_alternate("hello world", 6);
+/// assert_eq!(format!("{}", value), "hello…");
+/// assert_eq!(format!("{:#}", value), "hello world");
+/// ```
+pub fn compact_unless_alternate<T>(
+    value: T,
+    max_len: usize,
+) -> CompactUnlessAlternate<T> {
+    CompactUnlessAlternate { value, max_len }
+}
+
+/// Shortens to at most the [`Formatter::width()`] in [`char`]s.
+///
+/// Unlike the usual meaning of width as a minimum, the width is used as a
+/// maximum. Fill and alignment are ignored, and no ellipsis is written. If no
+/// width is given, the value is written in full.
+///
+/// This is equivalent to <code>[truncate_chars]\(value, width\)</code> with the
+/// width taken from the format string.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::max_width("hello world");
+/// assert_eq!(format!("{:5}", value), "hello");
+/// assert_eq!(format!("{:20}", value), "hello world");
+/// assert_eq!(format!("{}", value), "hello world");
+/// ```
+pub fn max_width<T>(value: T) -> MaxWidth<T> {
+    MaxWidth { value }
+}
+
+/// Shortens to the last `len` [`char`]s.
+///
+/// Because the number of [`char`]s to skip must be known before writing, the
+/// value is formatted twice.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::tail_chars("/home/user/file.txt", 8);
+/// assert_eq!(value.to_string(), "file.txt");
+/// ```
+pub fn tail_chars<T>(value: T, len: usize) -> TailChars<T> {
+    tail_chars_with(value, len, "")
+}
+
+/// Shortens to the last `len` [`char`]s by removing [`char`]s from the start.
+///
+/// This is the counterpart of [`truncate_chars()`]. Rather than buffering the
+/// end of the value, the value is formatted twice: once to count its [`char`]s
+/// and once to write all but the first ones. This needs no allocation or
+/// bounded buffer, so it works for any length.
+///
+/// This is equivalent to <code>[tail_chars]\(value, len\)</code>.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::truncate_start("src/truncate.rs", 11);
+/// assert_eq!(value.to_string(), "truncate.rs");
+///
+/// let value = fmty::truncate_start("abc", 5);
+/// assert_eq!(value.to_string(), "abc");
+/// ```
+pub fn truncate_start<T>(value: T, len: usize) -> TruncateStart<T> {
+    tail_chars(value, len)
+}
+
+/// Shortens to the last `len` [`char`]s, preceded by `ellipsis` if any were
+/// removed.
+///
+/// The ellipsis does not count toward `len`. See [`tail_chars()`] for details.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::tail_chars_with("/home/user/file.txt", 8, "…");
+/// assert_eq!(value.to_string(), "…file.txt");
+///
+/// let value = fmty::tail_chars_with("file.txt", 8, "…");
+/// assert_eq!(value.to_string(), "file.txt");
+/// ```
+pub fn tail_chars_with<T, E>(
+    value: T,
+    len: usize,
+    ellipsis: E,
+) -> TailChars<T, E> {
+    TailChars { value, len, ellipsis }
+}
+
+/// Shortens to at most `max_chars` [`char`]s without splitting words, followed
+/// by `ellipsis` if shortened.
+///
+/// The value is cut at the end of the last word that fits, so whitespace
+/// before the cut is not written. If the first word alone is longer than
+/// `max_chars`, it is cut at `max_chars`. The ellipsis does not count toward
+/// `max_chars`.
+///
+/// Because the cut must be found before writing, the value is formatted
+/// twice. The first pass stops after `max_chars + 1` [`char`]s.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::truncate_words("hello big world", 12, "…");
+/// assert_eq!(value.to_string(), "hello big…");
+///
+/// let value = fmty::truncate_words("hello", 5, "…");
+/// assert_eq!(value.to_string(), "hello");
+/// ```
+pub fn truncate_words<T, E>(
+    value: T,
+    max_chars: usize,
+    ellipsis: E,
+) -> TruncateWords<T, E> {
+    TruncateWords { value, max_chars, ellipsis }
+}
+
+/// Shortens to at most `max_bytes` bytes of UTF-8.
+///
+/// Only whole [`char`]s are written, so a [`char`] that would cross the limit
+/// is dropped along with everything after it. This is useful for writing into
+/// fixed-size buffers.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::truncate_bytes("héllo", 3);
+/// assert_eq!(value.to_string(), "hé");
+///
+/// let value = fmty::truncate_bytes("héllo", 2);
+/// assert_eq!(value.to_string(), "h");
+/// ```
+pub fn truncate_bytes<T>(value: T, max_bytes: usize) -> TruncateBytes<T> {
+    TruncateBytes { value, max_bytes }
+}
+
+/// Shortens to `len` [`char`]s, followed by `suffix` if anything was cut.
+///
+/// Values of at most `len` [`char`]s are written unchanged. The suffix does not
+/// count toward `len`.
+///
+/// Unlike [`truncate_words()`], this formats the value once, stopping at the
+/// first [`char`] past `len`.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::truncate_with("hello world", 5, "…");
+/// assert_eq!(value.to_string(), "hello…");
+///
+/// let value = fmty::truncate_with("hello", 5, "…");
+/// assert_eq!(value.to_string(), "hello");
+/// ```
+pub fn truncate_with<T, S>(
+    value: T,
+    len: usize,
+    suffix: S,
+) -> TruncateWith<T, S> {
+    TruncateWith { value, len, suffix }
+}
+
+/// Shortens to the first `prefix_len` and last `suffix_len` [`char`]s, with
+/// `ellipsis` between them if any were removed.
+///
+/// Values of at most `prefix_len + suffix_len` [`char`]s are written
+/// unchanged. The ellipsis does not count toward either length. This is useful
+/// for long hashes and identifiers.
+///
+/// Rather than buffering the end of the value, the value is formatted up to
+/// three times: once to count its [`char`]s, once to write the prefix, and once
+/// to write the suffix. This needs no allocation or bounded buffer, so it works
+/// in `no_std` for any length.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::truncate_middle("abcdefghij", 3, 2, "…");
+/// assert_eq!(value.to_string(), "abc…ij");
+///
+/// let value = fmty::truncate_middle("abcde", 3, 2, "…");
+/// assert_eq!(value.to_string(), "abcde");
+/// ```
+pub fn truncate_middle<T, E>(
+    value: T,
+    prefix_len: usize,
+    suffix_len: usize,
+    ellipsis: E,
+) -> TruncateMiddle<T, E> {
+    TruncateMiddle { value, prefix_len, suffix_len, ellipsis }
+}
+
+/// Shortens to `len` extended grapheme clusters.
+///
+/// Unlike [`truncate_chars()`], this never splits a user-perceived character,
+/// such as an emoji sequence or a letter with combining accents. Clusters are
+/// found by [`unicode-segmentation`](https://docs.rs/unicode-segmentation).
+///
+/// Because a cluster may span multiple writes, the current cluster is held in
+/// a fixed 64-byte buffer until the next one starts. Longer clusters are
+/// written as soon as they fill the buffer, and their remainder counts as
+/// separate clusters. This requires the `grapheme` feature.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::truncate_graphemes("🇯🇵🇫🇷🇧🇷", 2);
+/// assert_eq!(value.to_string(), "🇯🇵🇫🇷");
+///
+/// let value = fmty::truncate_graphemes("e\u{301}te\u{301}", 2);
+/// assert_eq!(value.to_string(), "e\u{301}t");
+/// ```
+#[cfg(feature = "grapheme")]
+pub fn truncate_graphemes<T>(value: T, len: usize) -> TruncateGraphemes<T> {
+    TruncateGraphemes { value, len }
+}
+
+/// Writes `value` without its first `skip` [`char`]s.
+pub(crate) fn write_skipping_chars<T: Display + ?Sized>(
+    f: &mut Formatter,
+    value: &T,
+    skip: usize,
+) -> Result {
+    struct Writer<'a, 'b> {
+        f: &'a mut Formatter<'b>,
+        rem_skip: usize,
+    }
+
+    impl Write for Writer<'_, '_> {
+        fn write_str(&mut self, s: &str) -> Result {
+            if self.rem_skip == 0 {
+                return self.f.write_str(s);
+            }
+
+            match s.char_indices().nth(self.rem_skip) {
+                Some((i, _)) => {
+                    self.rem_skip = 0;
+                    self.f.write_str(&s[i..])
+                }
+                None => {
+                    self.rem_skip -= s.chars().count();
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    write!(Writer { f, rem_skip: skip }, "{}", value)
+}
+
+/// Writes at most `rem_len` [`char`]s.
+struct TruncateCharsWriter<'a, 'b> {
+    f: &'a mut Formatter<'b>,
+    rem_len: usize,
+}
+
+impl Write for TruncateCharsWriter<'_, '_> {
+    fn write_str(&mut self, mut s: &str) -> Result {
+        if self.rem_len == 0 {
+            return Ok(());
+        }
+
+        // We want to `.take()` 1 past `rem_len` so that we get the byte
+        // index of where the last target `char` ends.
+        let take_len = match self.rem_len.checked_add(1) {
+            Some(n) => n,
+            None => return self.f.write_str(s),
+        };
+
+        if let Some((char_offset, (byte_offset, _))) =
+            s.char_indices().enumerate().take(take_len).last()
+        {
+            if char_offset == self.rem_len {
+                s = &s[..byte_offset];
+                self.rem_len = 0;
+            } else {
+                self.rem_len -= char_offset + 1;
+            }
+        } else {
+            // Empty iterator.
+            return Ok(());
+        }
+
+        self.f.write_str(s)
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> Result {
+        if let Some(rem_len) = self.rem_len.checked_sub(1) {
+            self.rem_len = rem_len;
+            self.f.write_char(c)
+        } else {
+            Ok(())
+        }
+    }
+
+    #[inline]
+    fn write_fmt(&mut self, args: Arguments) -> Result {
+        if self.rem_len == 0 {
+            Ok(())
+        } else {
+            write(self, args)
+        }
+    }
+}
+
+impl<T: Debug> Debug for TruncateChars<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let mut writer = TruncateCharsWriter { f, rem_len: self.len };
+        write!(writer, "{:?}", self.value)
+    }
+}
+
 impl<T: Display> Display for TruncateChars<T> {
     fn fmt(&self, f: &mut Formatter) -> Result {
+        let mut writer = TruncateCharsWriter { f, rem_len: self.len };
+        write!(writer, "{}", self.value)
+    }
+}
+
+impl<T: Display> Display for CompactUnlessAlternate<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        if f.alternate() {
+            return write!(f, "{}", self.value);
+        }
+
+        if self.max_len == 0 {
+            return Ok(());
+        }
+
         struct Writer<'a, 'b> {
             f: &'a mut Formatter<'b>,
             rem_len: usize,
+
+            // Held back until we know whether it is replaced by `…`.
+            last: Option<char>,
+            truncated: bool,
         }
 
         impl Write for Writer<'_, '_> {
-            fn write_str(&mut self, mut s: &str) -> Result {
-                if self.rem_len == 0 {
+            fn write_str(&mut self, s: &str) -> Result {
+                if self.truncated {
                     return Ok(());
                 }
 
-                // We want to `.take()` 1 past `rem_len` so that we get the byte
-                // index of where the last target `char` ends.
-                let take_len = match self.rem_len.checked_add(1) {
-                    Some(n) => n,
-                    None => return self.f.write_str(s),
+                let (head, tail) = match s.char_indices().nth(self.rem_len) {
+                    Some((i, _)) => s.split_at(i),
+                    None => (s, ""),
                 };
 
-                if let Some((char_offset, (byte_offset, _))) =
-                    s.char_indices().enumerate().take(take_len).last()
-                {
-                    if char_offset == self.rem_len {
-                        s = &s[..byte_offset];
-                        self.rem_len = 0;
-                    } else {
-                        self.rem_len -= char_offset + 1;
-                    }
+                if tail.is_empty() {
+                    self.rem_len -= head.chars().count();
                 } else {
-                    // Empty iterator.
-                    return Ok(());
+                    self.rem_len = 0;
                 }
+                self.f.write_str(head)?;
 
-                self.f.write_str(s)
+                for c in tail.chars() {
+                    self.write_char(c)?;
+                }
+                Ok(())
             }
 
-            #[inline]
             fn write_char(&mut self, c: char) -> Result {
                 if let Some(rem_len) = self.rem_len.checked_sub(1) {
                     self.rem_len = rem_len;
                     self.f.write_char(c)
                 } else {
+                    if self.last.is_some() {
+                        self.truncated = true;
+                    } else {
+                        self.last = Some(c);
+                    }
                     Ok(())
                 }
             }
+        }
+
+        let mut writer = Writer {
+            f,
+            rem_len: self.max_len - 1,
+            last: None,
+            truncated: false,
+        };
+        write!(writer, "{}", self.value)?;
+
+        match writer.last {
+            Some(_) if writer.truncated => writer.f.write_char('…'),
+            Some(c) => writer.f.write_char(c),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: Display> Display for MaxWidth<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match f.width() {
+            Some(width) => write!(f, "{}", truncate_chars(&self.value, width)),
+            None => write!(f, "{}", self.value),
+        }
+    }
+}
+
+impl<T: Display, E: Display> Display for TailChars<T, E> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let skip = char_len(&self.value)?.saturating_sub(self.len);
+
+        if skip != 0 {
+            write!(f, "{}", self.ellipsis)?;
+        }
+
+        write_skipping_chars(f, &self.value, skip)
+    }
+}
+
+impl<T: Display, E: Display> Display for TruncateWords<T, E> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        /// Finds where to cut, stopping once the value is known to be long.
+        struct Probe {
+            max_chars: usize,
+            len: usize,
+            prev_is_space: bool,
+
+            // The end of the last word that fits.
+            word_end: Option<usize>,
+        }
+
+        impl Write for Probe {
+            fn write_str(&mut self, s: &str) -> Result {
+                for c in s.chars() {
+                    if self.len > self.max_chars {
+                        return Err(Error);
+                    }
+
+                    let is_space = c.is_whitespace();
+                    if is_space && !self.prev_is_space && self.len != 0 {
+                        self.word_end = Some(self.len);
+                    }
+                    self.prev_is_space = is_space;
+                    self.len += 1;
+                }
+                Ok(())
+            }
+        }
+
+        let mut probe = Probe {
+            max_chars: self.max_chars,
+            len: 0,
+            prev_is_space: false,
+            word_end: None,
+        };
+
+        if write!(probe, "{}", self.value).is_ok()
+            && probe.len <= self.max_chars
+        {
+            return write!(f, "{}", self.value);
+        }
+
+        let len = probe.word_end.unwrap_or(self.max_chars);
+        write!(f, "{}{}", truncate_chars(&self.value, len), self.ellipsis)
+    }
+}
+
+impl<T: Display> Display for TruncateBytes<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        struct Writer<'a, 'b> {
+            f: &'a mut Formatter<'b>,
+            rem_bytes: usize,
+        }
+
+        impl Write for Writer<'_, '_> {
+            fn write_str(&mut self, s: &str) -> Result {
+                if s.len() <= self.rem_bytes {
+                    self.rem_bytes -= s.len();
+                    return self.f.write_str(s);
+                }
+
+                // Cut before the first `char` that ends past the limit.
+                let end = s
+                    .char_indices()
+                    .find(|&(i, c)| i + c.len_utf8() > self.rem_bytes)
+                    .map_or(s.len(), |(i, _)| i);
+
+                // Nothing after the cut may be written, even if it fits.
+                self.rem_bytes = 0;
+                self.f.write_str(&s[..end])
+            }
 
             #[inline]
             fn write_fmt(&mut self, args: Arguments) -> Result {
-                if self.rem_len == 0 {
+                if self.rem_bytes == 0 {
                     Ok(())
                 } else {
                     write(self, args)
@@ -87,7 +608,140 @@ impl<T: Display> Display for TruncateChars<T> {
             }
         }
 
-        write!(Writer { f, rem_len: self.len }, "{}", self.value)
+        write!(Writer { f, rem_bytes: self.max_bytes }, "{}", self.value)
+    }
+}
+
+impl<T: Display, S: Display> Display for TruncateWith<T, S> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        struct Writer<'a, 'b> {
+            f: &'a mut Formatter<'b>,
+            rem_len: usize,
+            truncated: bool,
+        }
+
+        impl Write for Writer<'_, '_> {
+            fn write_str(&mut self, s: &str) -> Result {
+                if self.truncated {
+                    return Ok(());
+                }
+
+                match s.char_indices().nth(self.rem_len) {
+                    Some((i, _)) => {
+                        self.rem_len = 0;
+                        self.truncated = true;
+                        self.f.write_str(&s[..i])
+                    }
+                    None => {
+                        self.rem_len -= s.chars().count();
+                        self.f.write_str(s)
+                    }
+                }
+            }
+
+            #[inline]
+            fn write_fmt(&mut self, args: Arguments) -> Result {
+                if self.truncated {
+                    Ok(())
+                } else {
+                    write(self, args)
+                }
+            }
+        }
+
+        let mut writer = Writer { f, rem_len: self.len, truncated: false };
+        write!(writer, "{}", self.value)?;
+
+        if writer.truncated {
+            write!(writer.f, "{}", self.suffix)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Display, E: Display> Display for TruncateMiddle<T, E> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let len = char_len(&self.value)?;
+
+        match self.prefix_len.checked_add(self.suffix_len) {
+            Some(keep) if keep < len => {}
+            _ => return write!(f, "{}", self.value),
+        }
+
+        write!(
+            f,
+            "{}{}",
+            truncate_chars(&self.value, self.prefix_len),
+            self.ellipsis,
+        )?;
+        write_skipping_chars(f, &self.value, len - self.suffix_len)
+    }
+}
+
+#[cfg(feature = "grapheme")]
+impl<T: Display> Display for TruncateGraphemes<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        use unicode_segmentation::UnicodeSegmentation;
+
+        use crate::buf::InlineStr;
+
+        struct Writer<'a, 'b> {
+            f: &'a mut Formatter<'b>,
+            rem_len: usize,
+
+            // The current cluster, which may continue in the next write.
+            cluster: InlineStr<64>,
+        }
+
+        impl Writer<'_, '_> {
+            /// Writes the current cluster if there is room for it.
+            fn end_cluster(&mut self) -> Result {
+                if self.rem_len != 0 {
+                    self.rem_len -= 1;
+                    self.f.write_str(self.cluster.as_str())?;
+                }
+                self.cluster.clear();
+                Ok(())
+            }
+        }
+
+        impl Write for Writer<'_, '_> {
+            fn write_str(&mut self, s: &str) -> Result {
+                for c in s.chars() {
+                    if self.rem_len == 0 {
+                        return Ok(());
+                    }
+
+                    if !self.cluster.try_push(c) {
+                        self.end_cluster()?;
+                        self.cluster.try_push(c);
+                        continue;
+                    }
+
+                    // Appending `c` can only add a boundary right before it.
+                    let mut clusters = self.cluster.as_str().graphemes(true);
+                    if clusters.nth(1).is_some() {
+                        let end = self.cluster.as_str().len() - c.len_utf8();
+
+                        self.rem_len -= 1;
+                        self.f.write_str(&self.cluster.as_str()[..end])?;
+                        self.cluster.clear();
+                        self.cluster.try_push(c);
+                    }
+                }
+                Ok(())
+            }
+        }
+
+        let mut writer =
+            Writer { f, rem_len: self.len, cluster: InlineStr::new() };
+        write!(writer, "{}", self.value)?;
+
+        if writer.cluster.is_empty() {
+            Ok(())
+        } else {
+            writer.end_cluster()
+        }
     }
 }
 
@@ -140,4 +794,315 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn debug() {
+        let expected = &"[1, 2, 3]";
+
+        for len in 0..=expected.len() {
+            let expected = &expected[..len];
+
+            assert_eq!(
+                format!("{:?}", truncate_chars(vec![1, 2, 3], len)),
+                expected,
+                "incorrect result for length {len}",
+            );
+            assert_eq!(
+                format!(
+                    "{:?}",
+                    truncate_chars(format_args!("{:?}", [1, 2, 3]), len)
+                ),
+                expected,
+                "incorrect result for length {len}",
+            );
+        }
+
+        let value = truncate_chars(Some(("é", -1)), 9);
+        assert_eq!(format!("{:?}", value), "Some((\"é\"");
+    }
+
+    #[test]
+    fn compact() {
+        let cases = [
+            (0, ""),
+            (1, "…"),
+            (2, "a…"),
+            (5, "abcd…"),
+            (6, "abcdef"),
+            (7, "abcdef"),
+        ];
+
+        for (len, expected) in cases {
+            let value = crate::concat_tuple(("abc", 'd', "ef"));
+            let value = compact_unless_alternate(value, len);
+            assert_eq!(format!("{}", value), expected, "for length {len}");
+            assert_eq!(format!("{:#}", value), "abcdef", "for length {len}");
+        }
+
+        let value =
+            compact_unless_alternate(crate::concat_tuple(('é', "ü")), 2);
+        assert_eq!(format!("{}", value), "éü");
+    }
+
+    #[test]
+    fn max_widths() {
+        let value = max_width(crate::concat_tuple(("héllo", ' ', 42)));
+
+        assert_eq!(format!("{}", value), "héllo 42");
+        assert_eq!(format!("{:1$}", value, 0), "");
+        assert_eq!(format!("{:2}", value), "hé");
+        assert_eq!(format!("{:>7}", value), "héllo 4");
+        assert_eq!(format!("{:*^8}", value), "héllo 42");
+        assert_eq!(format!("{:1$}", value, 99), "héllo 42");
+    }
+
+    #[test]
+    fn tail() {
+        let expected = "abc123xyz";
+
+        for len in 0..=expected.len() + 1 {
+            let value = crate::concat_tuple(("abc", 123, 'x', "yz"));
+            let expected = &expected[expected.len().saturating_sub(len)..];
+
+            assert_eq!(
+                tail_chars(value, len).to_string(),
+                expected,
+                "incorrect result for length {len}",
+            );
+        }
+
+        assert_eq!(tail_chars("", 3).to_string(), "");
+        assert_eq!(tail_chars("déjà vu", 5).to_string(), "jà vu");
+    }
+
+    #[test]
+    fn tail_with() {
+        let value = crate::concat_tuple(("abc", 'd', "ef"));
+
+        assert_eq!(tail_chars_with(value, 0, "…").to_string(), "…");
+        assert_eq!(tail_chars_with(value, 2, "…").to_string(), "…ef");
+        assert_eq!(tail_chars_with(value, 5, "...").to_string(), "...bcdef");
+
+        // Shorter than or exactly `len`.
+        assert_eq!(tail_chars_with(value, 6, "…").to_string(), "abcdef");
+        assert_eq!(tail_chars_with(value, 9, "…").to_string(), "abcdef");
+        assert_eq!(tail_chars_with("", 0, "…").to_string(), "");
+    }
+
+    #[test]
+    fn words() {
+        let value = "the quick  brown fox";
+        let cases = [
+            (0, "…"),
+            (2, "th…"),
+            (3, "the…"),
+            (8, "the…"),
+            (9, "the quick…"),
+            (10, "the quick…"),
+            (11, "the quick…"),
+            (15, "the quick…"),
+            (16, "the quick  brown…"),
+            (19, "the quick  brown…"),
+            (20, "the quick  brown fox"),
+            (99, "the quick  brown fox"),
+        ];
+
+        for (max, expected) in cases {
+            assert_eq!(
+                truncate_words(value, max, "…").to_string(),
+                expected,
+                "incorrect result for max {max}",
+            );
+        }
+    }
+
+    #[test]
+    fn words_edge_cases() {
+        // A single long word is cut.
+        assert_eq!(truncate_words("abcdef", 4, "...").to_string(), "abcd...");
+        assert_eq!(
+            truncate_words("abcdef ghi", 4, "...").to_string(),
+            "abcd...",
+        );
+
+        // Leading whitespace is not a word boundary.
+        assert_eq!(truncate_words("  abcdef", 4, "…").to_string(), "  ab…");
+
+        // Words split across writes.
+        let value = crate::concat_tuple(("ab", ' ', "cd", "ef gh"));
+        assert_eq!(truncate_words(value, 8, "…").to_string(), "ab cdef…");
+        assert_eq!(truncate_words(value, 5, "…").to_string(), "ab…");
+
+        assert_eq!(truncate_words("", 0, "…").to_string(), "");
+        assert_eq!(truncate_words("a b\nc", 4, "…").to_string(), "a b…");
+    }
+
+    #[test]
+    fn bytes() {
+        let expected = "abc123xyz";
+
+        for len in 0..=expected.len() + 1 {
+            let expected = &expected[..len.min(expected.len())];
+
+            assert_eq!(
+                truncate_bytes(format_args!("abc{}{}", 123, "xyz"), len)
+                    .to_string(),
+                expected,
+                "incorrect result for length {len}",
+            );
+        }
+    }
+
+    #[test]
+    fn bytes_boundary() {
+        // 'é' is 2 bytes and '€' is 3 bytes.
+        let cases = [
+            (0, ""),
+            (1, "a"),
+            (2, "a"),
+            (3, "aé"),
+            (4, "aé"),
+            (5, "aé"),
+            (6, "aé€"),
+            (7, "aé€b"),
+            (8, "aé€b"),
+        ];
+
+        for (len, expected) in cases {
+            assert_eq!(
+                truncate_bytes(format_args!("a{}{}b", 'é', "€"), len)
+                    .to_string(),
+                expected,
+                "incorrect result for length {len}",
+            );
+        }
+
+        // Nothing is written after a dropped `char`, even if it would fit.
+        assert_eq!(
+            truncate_bytes(format_args!("a€{}", 'b'), 3).to_string(),
+            "a"
+        );
+        assert_eq!(truncate_bytes("a€b", 3).to_string(), "a");
+    }
+
+    #[test]
+    fn with_suffix() {
+        assert_eq!(truncate_with("abc", 5, "…").to_string(), "abc");
+        assert_eq!(truncate_with("abc", 3, "…").to_string(), "abc");
+        assert_eq!(truncate_with("abcdef", 3, "…").to_string(), "abc…");
+        assert_eq!(truncate_with("abc", 0, "…").to_string(), "…");
+        assert_eq!(truncate_with("", 0, "…").to_string(), "");
+
+        let expected = "abc123xyz";
+
+        for len in 0..=expected.len() {
+            let suffix = if len < expected.len() { "..." } else { "" };
+            let expected = format!("{}{}", &expected[..len], suffix);
+
+            assert_eq!(
+                truncate_with(format_args!("abc{}{}", 123, "xyz"), len, "...")
+                    .to_string(),
+                expected,
+                "incorrect result for length {len}",
+            );
+        }
+
+        // Empty writes after the cut point do not count as truncation.
+        let value = crate::concat_tuple(("ab", "", 'c', ""));
+        assert_eq!(truncate_with(value, 3, "…").to_string(), "abc");
+    }
+
+    #[test]
+    fn start() {
+        assert_eq!(truncate_start("abcdef", 3).to_string(), "def");
+        assert_eq!(truncate_start("abcdef", 0).to_string(), "");
+        assert_eq!(truncate_start("abcdef", 6).to_string(), "abcdef");
+        assert_eq!(truncate_start("abcdef", 100).to_string(), "abcdef");
+        assert_eq!(truncate_start("", 3).to_string(), "");
+
+        let value = crate::concat_tuple(("ab", 'c', "déf"));
+        assert_eq!(truncate_start(value, 4).to_string(), "cdéf");
+    }
+
+    #[test]
+    fn middle() {
+        let cases = [
+            (3, 2, "abc…ij"),
+            (0, 2, "…ij"),
+            (3, 0, "abc…"),
+            (0, 0, "…"),
+            (5, 5, "abcdefghij"),
+            (9, 1, "abcdefghij"),
+            (8, 1, "abcdefgh…j"),
+            (20, 20, "abcdefghij"),
+            (usize::MAX, 1, "abcdefghij"),
+        ];
+
+        for (prefix_len, suffix_len, expected) in cases {
+            let value = crate::concat_tuple(("abc", 'd', "efghij"));
+
+            assert_eq!(
+                truncate_middle(value, prefix_len, suffix_len, "…").to_string(),
+                expected,
+                "incorrect result for lengths {prefix_len}, {suffix_len}",
+            );
+        }
+
+        assert_eq!(truncate_middle("", 0, 0, "…").to_string(), "");
+        assert_eq!(truncate_middle("éàü", 1, 1, "..").to_string(), "é..ü");
+    }
+
+    #[test]
+    #[cfg(feature = "grapheme")]
+    fn graphemes() {
+        let family = "👨\u{200d}👩\u{200d}👧";
+        let value = crate::concat_tuple(("a", family, "e\u{301}", "🇯🇵"));
+
+        let cases = [
+            (0, String::new()),
+            (1, "a".to_owned()),
+            (2, format!("a{}", family)),
+            (3, format!("a{}e\u{301}", family)),
+            (4, format!("a{}e\u{301}🇯🇵", family)),
+            (5, format!("a{}e\u{301}🇯🇵", family)),
+        ];
+
+        for (len, expected) in cases {
+            assert_eq!(
+                truncate_graphemes(value, len).to_string(),
+                expected,
+                "incorrect result for length {len}",
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "grapheme")]
+    fn graphemes_split_writes() {
+        // Clusters that span writes are still counted as one.
+        let flag = crate::concat_tuple(('🇫', '🇷', "🇧", "🇷", '🇯', "🇵"));
+        assert_eq!(truncate_graphemes(flag, 1).to_string(), "🇫🇷");
+        assert_eq!(truncate_graphemes(flag, 2).to_string(), "🇫🇷🇧🇷");
+
+        let accents =
+            crate::concat_tuple(("e", '\u{301}', "\u{302}x", "", 'y'));
+        assert_eq!(
+            truncate_graphemes(accents, 1).to_string(),
+            "e\u{301}\u{302}"
+        );
+        assert_eq!(
+            truncate_graphemes(accents, 2).to_string(),
+            "e\u{301}\u{302}x",
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "grapheme")]
+    fn graphemes_overflow() {
+        // A cluster longer than the buffer is split at the buffer size.
+        let zalgo = format!("a{}b", "\u{301}".repeat(40));
+        let expected = format!("a{}", "\u{301}".repeat(31));
+
+        assert_eq!(truncate_graphemes(&zalgo, 1).to_string(), expected);
+    }
 }
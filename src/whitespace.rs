@@ -0,0 +1,318 @@
+use core::fmt::*;
+
+use crate::pad::write_fill;
+
+pub(crate) mod types {
+    #[allow(unused)]
+    use super::*;
+
+    /// See [`show_whitespace()`], [`show_whitespace_with()`].
+    #[derive(Clone, Copy)]
+    pub struct ShowWhitespace<T> {
+        pub(super) value: T,
+        pub(super) space: char,
+        pub(super) tab: char,
+        pub(super) newline: char,
+    }
+
+    /// See [`single_line()`], [`single_line_escaped()`].
+    #[derive(Clone, Copy)]
+    pub struct SingleLine<T> {
+        pub(super) value: T,
+        pub(super) escaped: bool,
+    }
+
+    /// See [`ensure_trailing_newline()`].
+    #[derive(Clone, Copy)]
+    pub struct EnsureTrailingNewline<T> {
+        pub(super) value: T,
+    }
+}
+
+use types::*;
+
+/// Makes spaces, tabs, and newlines visible by replacing them with `·`, `→`,
+/// and `¶`.
+///
+/// Newlines are kept after `¶` so that lines stay separate. This is equivalent
+/// to <code>[show_whitespace_with]\(value, '·', '→', '¶'\)</code>.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::show_whitespace("a b\tc\n");
+/// assert_eq!(value.to_string(), "a·b→c¶\n");
+/// ```
+pub fn show_whitespace<T>(value: T) -> ShowWhitespace<T> {
+    show_whitespace_with(value, '·', '→', '¶')
+}
+
+/// Makes spaces, tabs, and newlines visible by replacing them with the given
+/// glyphs.
+///
+/// Newlines are kept after the `newline` glyph so that lines stay separate.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::show_whitespace_with("a b\tc\n", '_', '>', '$');
+/// assert_eq!(value.to_string(), "a_b>c$\n");
+/// ```
+pub fn show_whitespace_with<T>(
+    value: T,
+    space: char,
+    tab: char,
+    newline: char,
+) -> ShowWhitespace<T> {
+    ShowWhitespace { value, space, tab, newline }
+}
+
+/// Writes a value on a single line by replacing each `\n` or `\r\n` with a
+/// space.
+///
+/// This keeps multi-line values from breaking up line-oriented logs. A `\r`
+/// not followed by `\n` is written unchanged.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::single_line("first\r\nsecond\nthird");
+/// assert_eq!(value.to_string(), "first second third");
+/// ```
+pub fn single_line<T>(value: T) -> SingleLine<T> {
+    SingleLine { value, escaped: false }
+}
+
+/// Writes a value on a single line by replacing each `\n` or `\r\n` with the
+/// escape sequence `\n`.
+///
+/// Unlike [`single_line()`], line breaks remain visible. See
+/// [`single_line()`] for details.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::single_line_escaped("first\r\nsecond\nthird");
+/// assert_eq!(value.to_string(), r"first\nsecond\nthird");
+/// ```
+pub fn single_line_escaped<T>(value: T) -> SingleLine<T> {
+    SingleLine { value, escaped: true }
+}
+
+/// Writes a value so that it ends with exactly one `\n`.
+///
+/// A `\n` is appended if the value does not end with one, and multiple
+/// trailing `\n`s are collapsed into one. This is useful when writing text
+/// files. Empty values write a single `\n`.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::ensure_trailing_newline("line");
+/// assert_eq!(value.to_string(), "line\n");
+///
+/// let value = fmty::ensure_trailing_newline("line\n\n\n");
+/// assert_eq!(value.to_string(), "line\n");
+/// ```
+pub fn ensure_trailing_newline<T>(value: T) -> EnsureTrailingNewline<T> {
+    EnsureTrailingNewline { value }
+}
+
+impl<T: Display> Display for ShowWhitespace<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        struct Writer<'a, 'b> {
+            f: &'a mut Formatter<'b>,
+            glyphs: [char; 3],
+        }
+
+        impl Write for Writer<'_, '_> {
+            fn write_str(&mut self, s: &str) -> Result {
+                let [space, tab, newline] = self.glyphs;
+                let mut rest = s;
+
+                while let Some(i) = rest.find(&[' ', '\t', '\n'][..]) {
+                    self.f.write_str(&rest[..i])?;
+                    match rest.as_bytes()[i] {
+                        b' ' => self.f.write_char(space)?,
+                        b'\t' => self.f.write_char(tab)?,
+                        _ => {
+                            self.f.write_char(newline)?;
+                            self.f.write_char('\n')?;
+                        }
+                    }
+                    rest = &rest[i + 1..];
+                }
+
+                self.f.write_str(rest)
+            }
+        }
+
+        let glyphs = [self.space, self.tab, self.newline];
+        write!(Writer { f, glyphs }, "{}", self.value)
+    }
+}
+
+impl<T: Display> Display for SingleLine<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        struct Writer<'a, 'b> {
+            f: &'a mut Formatter<'b>,
+            newline: &'static str,
+
+            // Held back until we know whether it starts `\r\n`.
+            pending_cr: bool,
+        }
+
+        impl Write for Writer<'_, '_> {
+            fn write_str(&mut self, s: &str) -> Result {
+                let mut rest = s;
+
+                if self.pending_cr && !rest.is_empty() {
+                    self.pending_cr = false;
+
+                    if rest.starts_with('\n') {
+                        self.f.write_str(self.newline)?;
+                        rest = &rest[1..];
+                    } else {
+                        self.f.write_char('\r')?;
+                    }
+                }
+
+                while let Some(i) = rest.find(&['\r', '\n'][..]) {
+                    self.f.write_str(&rest[..i])?;
+
+                    if rest.as_bytes()[i] == b'\n' {
+                        self.f.write_str(self.newline)?;
+                        rest = &rest[i + 1..];
+                    } else if rest[i + 1..].is_empty() {
+                        self.pending_cr = true;
+                        return Ok(());
+                    } else if rest[i + 1..].starts_with('\n') {
+                        self.f.write_str(self.newline)?;
+                        rest = &rest[i + 2..];
+                    } else {
+                        self.f.write_char('\r')?;
+                        rest = &rest[i + 1..];
+                    }
+                }
+
+                self.f.write_str(rest)
+            }
+        }
+
+        let newline = if self.escaped { r"\n" } else { " " };
+
+        let mut writer = Writer { f, newline, pending_cr: false };
+        write!(writer, "{}", self.value)?;
+
+        if writer.pending_cr {
+            writer.f.write_char('\r')?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Display> Display for EnsureTrailingNewline<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        struct Writer<'a, 'b> {
+            f: &'a mut Formatter<'b>,
+
+            // Held back until we know whether they are trailing.
+            newlines: usize,
+        }
+
+        impl Write for Writer<'_, '_> {
+            fn write_str(&mut self, s: &str) -> Result {
+                let text = s.trim_end_matches('\n');
+
+                if !text.is_empty() {
+                    write_fill(self.f, '\n', self.newlines)?;
+                    self.f.write_str(text)?;
+                    self.newlines = 0;
+                }
+
+                self.newlines += s.len() - text.len();
+                Ok(())
+            }
+        }
+
+        let mut writer = Writer { f, newlines: 0 };
+        write!(writer, "{}", self.value)?;
+
+        f.write_char('\n')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn show() {
+        assert_eq!(show_whitespace("").to_string(), "");
+        assert_eq!(show_whitespace("abc").to_string(), "abc");
+        assert_eq!(
+            show_whitespace("  if x {\n\t\treturn;\r\n}").to_string(),
+            "··if·x·{¶\n→→return;\r¶\n}",
+        );
+        assert_eq!(
+            show_whitespace(format_args!("{}{}", 1, "\t2 ")).to_string(),
+            "1→2·",
+        );
+    }
+
+    #[test]
+    fn show_with() {
+        let value = show_whitespace_with(" \t\n", '.', '-', '$');
+        assert_eq!(value.to_string(), ".-$\n");
+    }
+
+    #[test]
+    fn single() {
+        let cases = [
+            ("", "", ""),
+            ("one", "one", "one"),
+            ("a\nb", "a b", r"a\nb"),
+            ("a\r\nb\n", "a b ", r"a\nb\n"),
+            ("\n\n", "  ", r"\n\n"),
+            ("a\rb\r", "a\rb\r", "a\rb\r"),
+            ("a\r\r\nb", "a\r b", "a\r\\nb"),
+        ];
+
+        for (value, spaced, escaped) in cases {
+            assert_eq!(single_line(value).to_string(), spaced);
+            assert_eq!(single_line_escaped(value).to_string(), escaped);
+        }
+    }
+
+    #[test]
+    fn single_parts() {
+        // `\r\n` split across writes.
+        let value = crate::concat_tuple(("a\r", "\nb\r", "", '\n', "c\r", 'd'));
+        assert_eq!(single_line(value).to_string(), "a b c\rd");
+
+        let value = crate::concat_tuple(("a\r", '\r', "\n"));
+        assert_eq!(single_line_escaped(value).to_string(), "a\r\\n");
+    }
+
+    #[test]
+    fn trailing_newline() {
+        let cases = [
+            ("", "\n"),
+            ("\n", "\n"),
+            ("\n\n", "\n"),
+            ("a", "a\n"),
+            ("a\n", "a\n"),
+            ("a\n\n", "a\n"),
+            ("a\n\nb", "a\n\nb\n"),
+            ("\na \n", "\na \n"),
+        ];
+
+        for (value, expected) in cases {
+            assert_eq!(ensure_trailing_newline(value).to_string(), expected);
+        }
+
+        // Newlines split across writes.
+        let value = crate::concat_tuple(("a\n", '\n', "", "b\n", "\n\n"));
+        assert_eq!(ensure_trailing_newline(value).to_string(), "a\n\nb\n");
+    }
+}
@@ -0,0 +1,409 @@
+use core::fmt::*;
+
+use crate::{buf::InlineStr, fmt_with, measure::char_len, pad::write_fill};
+
+pub(crate) mod types {
+    #[allow(unused)]
+    use super::*;
+
+    /// See [`wrap_lines()`], [`hanging_wrap()`].
+    #[derive(Clone, Copy)]
+    pub struct WrapLines<T> {
+        pub(super) value: T,
+        pub(super) width: usize,
+        pub(super) hang: usize,
+    }
+
+    /// See [`join_wrap()`], [`join_wrap_indent()`].
+    #[derive(Clone, Copy)]
+    pub struct JoinWrap<I, S> {
+        pub(super) iter: I,
+        pub(super) sep: S,
+        pub(super) width: usize,
+        pub(super) indent: usize,
+    }
+}
+
+use types::*;
+
+/// Word-wraps each line to `width` [`char`]s.
+///
+/// Each line of the input is wrapped independently, so existing line breaks
+/// are preserved as paragraph boundaries. Within a line, runs of whitespace are
+/// collapsed into a single space, and leading and trailing whitespace is
+/// removed. Words longer than `width` are not split.
+///
+/// Words are buffered inline until it is known whether they fit on the current
+/// line. Words longer than this buffer (256 bytes) are always moved to a new
+/// line.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::wrap_lines("hola mundo\nhello world", 7);
+/// assert_eq!(value.to_string(), "hola\nmundo\nhello\nworld");
+/// ```
+pub fn wrap_lines<T>(value: T, width: usize) -> WrapLines<T> {
+    WrapLines { value, width, hang: 0 }
+}
+
+/// Word-wraps each line to `width` [`char`]s, indenting wrapped lines by
+/// `hang` spaces.
+///
+/// The first line of each paragraph is not indented, which is common in
+/// bibliographies and command-line option descriptions. The indent counts
+/// toward `width`. See [`wrap_lines()`] for details.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::hanging_wrap("-v, --verbose  print more output", 18, 4);
+/// assert_eq!(value.to_string(), "-v, --verbose\n    print more\n    output");
+/// ```
+pub fn hanging_wrap<T>(value: T, width: usize, hang: usize) -> WrapLines<T> {
+    WrapLines { value, width, hang }
+}
+
+/// Concatenates [`Iterator`] items with a separator between each, starting a
+/// new line before any item that would exceed `width` [`char`]s.
+///
+/// At a line break, the separator is written without trailing whitespace
+/// before the newline, and counts toward the line's width. Items wider than
+/// `width` are not split.
+///
+/// Because the width of each item must be known before it is written, each
+/// item is formatted twice.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::join_wrap(["alpha", "beta", "gamma", "delta"], ", ", 12);
+/// assert_eq!(value.to_string(), "alpha, beta,\ngamma, delta");
+/// ```
+pub fn join_wrap<I, S>(
+    iter: I,
+    sep: S,
+    width: usize,
+) -> JoinWrap<I::IntoIter, S>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+{
+    join_wrap_indent(iter, sep, width, 0)
+}
+
+/// Concatenates [`Iterator`] items with a separator between each, starting a
+/// new line indented by `indent` spaces before any item that would exceed
+/// `width` [`char`]s.
+///
+/// The indent counts toward `width`. See [`join_wrap()`] for details.
+///
+/// # Examples
+///
+/// ```
+/// let value = fmty::join_wrap_indent(1..=8, ", ", 12, 2);
+/// assert_eq!(value.to_string(), "1, 2, 3, 4,\n  5, 6, 7, 8");
+/// ```
+pub fn join_wrap_indent<I, S>(
+    iter: I,
+    sep: S,
+    width: usize,
+    indent: usize,
+) -> JoinWrap<I::IntoIter, S>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+{
+    JoinWrap { iter: iter.into_iter(), sep, width, indent }
+}
+
+/// Greedy word-wrapping writer.
+struct WrapWriter<'a, 'b> {
+    f: &'a mut Formatter<'b>,
+    width: usize,
+
+    /// The indent of wrapped lines.
+    hang: usize,
+
+    /// The indent of the current line.
+    indent: usize,
+
+    /// The number of `char`s on the current line, excluding the indent.
+    col: usize,
+
+    /// Whether the last `char` was part of a word.
+    in_word: bool,
+
+    /// The start of the current word, if not yet known to fit.
+    word: InlineStr<256>,
+    word_len: usize,
+}
+
+impl<'a, 'b> WrapWriter<'a, 'b> {
+    fn new(f: &'a mut Formatter<'b>, width: usize, hang: usize) -> Self {
+        Self {
+            f,
+            width,
+            hang,
+            indent: 0,
+            col: 0,
+            in_word: false,
+            word: InlineStr::new(),
+            word_len: 0,
+        }
+    }
+
+    /// Writes the buffered word on a new line.
+    fn break_word(&mut self) -> Result {
+        self.f.write_char('\n')?;
+        write_fill(self.f, ' ', self.hang)?;
+        self.f.write_str(self.word.as_str())?;
+        self.indent = self.hang;
+        self.col = self.word_len;
+        self.word.clear();
+        self.word_len = 0;
+        Ok(())
+    }
+
+    /// Completes the current word.
+    fn end_word(&mut self) -> Result {
+        self.in_word = false;
+
+        if self.word.is_empty() {
+            return Ok(());
+        }
+
+        // The word fits on the current line.
+        self.f.write_char(' ')?;
+        self.f.write_str(self.word.as_str())?;
+        self.col += 1 + self.word_len;
+        self.word.clear();
+        self.word_len = 0;
+        Ok(())
+    }
+
+    /// Completes the current line.
+    fn end_line(&mut self) -> Result {
+        self.end_word()?;
+        self.indent = 0;
+        self.col = 0;
+        self.f.write_char('\n')
+    }
+}
+
+impl Write for WrapWriter<'_, '_> {
+    fn write_str(&mut self, s: &str) -> Result {
+        for c in s.chars() {
+            self.write_char(c)?;
+        }
+        Ok(())
+    }
+
+    fn write_char(&mut self, c: char) -> Result {
+        if c == '\n' {
+            return self.end_line();
+        }
+
+        if c.is_whitespace() {
+            return self.end_word();
+        }
+
+        let word_start = !self.in_word;
+        self.in_word = true;
+
+        // Words at the start of a line are written immediately, as are the
+        // remaining `char`s of words that were moved to a new line.
+        if self.col == 0 || (!word_start && self.word.is_empty()) {
+            self.col += 1;
+            return self.f.write_char(c);
+        }
+
+        if !self.word.try_push(c) {
+            self.break_word()?;
+            self.col += 1;
+            return self.f.write_char(c);
+        }
+
+        self.word_len += 1;
+        if self.indent + self.col + 1 + self.word_len > self.width {
+            self.break_word()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Display> Display for WrapLines<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let mut writer = WrapWriter::new(f, self.width, self.hang);
+        write!(writer, "{}", self.value)?;
+        writer.end_word()
+    }
+}
+
+/// Writes without trailing whitespace.
+struct TrimEndWriter<W> {
+    f: W,
+    pending: InlineStr<32>,
+}
+
+impl<W> TrimEndWriter<W> {
+    fn new(f: W) -> Self {
+        Self { f, pending: InlineStr::new() }
+    }
+}
+
+impl<W: Write> Write for TrimEndWriter<W> {
+    fn write_char(&mut self, c: char) -> Result {
+        if !c.is_whitespace() {
+            self.f.write_str(self.pending.as_str())?;
+            self.pending.clear();
+            return self.f.write_char(c);
+        }
+
+        // Whitespace beyond the buffer is not trailing in practice.
+        if !self.pending.try_push(c) {
+            self.f.write_str(self.pending.as_str())?;
+            self.pending.clear();
+            self.f.write_char(c)?;
+        }
+        Ok(())
+    }
+
+    fn write_str(&mut self, s: &str) -> Result {
+        s.chars().try_for_each(|c| self.write_char(c))
+    }
+}
+
+impl<I, S> Display for JoinWrap<I, S>
+where
+    I: Iterator + Clone,
+    I::Item: Display,
+    S: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        // The separator before a line break is written without trailing
+        // whitespace, so it must also fit.
+        let sep_len = char_len(&self.sep)?;
+        let break_sep =
+            fmt_with(|f| write!(TrimEndWriter::new(f), "{}", self.sep));
+        let break_sep_len = char_len(&break_sep)?;
+
+        let mut iter = self.iter.clone().peekable();
+        let mut col = 0;
+        let mut is_first = true;
+
+        while let Some(item) = iter.next() {
+            let len = char_len(&item)?;
+            let end_len = if iter.peek().is_some() { break_sep_len } else { 0 };
+
+            if is_first {
+                col = len;
+                is_first = false;
+            } else if col + sep_len + len + end_len > self.width {
+                writeln!(f, "{}", break_sep)?;
+                write_fill(f, ' ', self.indent)?;
+                col = self.indent + len;
+            } else {
+                write!(f, "{}", self.sep)?;
+                col += sep_len + len;
+            }
+
+            write!(f, "{}", item)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paragraphs() {
+        let value =
+            wrap_lines("the quick brown fox jumps\nover the lazy dog", 10);
+
+        assert_eq!(
+            value.to_string(),
+            "the quick\nbrown fox\njumps\nover the\nlazy dog",
+        );
+    }
+
+    #[test]
+    fn blank_lines() {
+        let value = wrap_lines("aa bb cc\n\ndd ee", 5);
+        assert_eq!(value.to_string(), "aa bb\ncc\n\ndd ee");
+    }
+
+    #[test]
+    fn whitespace() {
+        let value = wrap_lines("  aa \t bb  \n  cc  ", 80);
+        assert_eq!(value.to_string(), "aa bb\ncc");
+    }
+
+    #[test]
+    fn long_word() {
+        let value = wrap_lines("a abcdefgh b", 4);
+        assert_eq!(value.to_string(), "a\nabcdefgh\nb");
+    }
+
+    #[test]
+    fn parts() {
+        assert_eq!(
+            wrap_lines(format_args!("ab{}cd ef", "c "), 6).to_string(),
+            "abc cd\nef",
+        );
+    }
+
+    #[test]
+    fn hanging() {
+        let value = hanging_wrap(
+            "Knuth, D. E. The Art of Computer Programming. Addison-Wesley.",
+            28,
+            4,
+        );
+        let expected = core::concat!(
+            "Knuth, D. E. The Art of\n",
+            "    Computer Programming.\n",
+            "    Addison-Wesley.",
+        );
+        assert_eq!(value.to_string(), expected);
+    }
+
+    #[test]
+    fn hanging_paragraphs() {
+        let value = hanging_wrap("aa bb cc\ndd ee ff", 6, 2);
+        assert_eq!(value.to_string(), "aa bb\n  cc\ndd ee\n  ff");
+
+        let value = hanging_wrap("aa bbbbbb", 4, 2);
+        assert_eq!(value.to_string(), "aa\n  bbbbbb");
+    }
+
+    #[test]
+    fn join() {
+        let words = ["one", "two", "three", "four", "five", "six"];
+
+        let value = join_wrap(words, " ", 9);
+        assert_eq!(value.to_string(), "one two\nthree\nfour five\nsix");
+
+        let value = join_wrap(words, ", ", 15);
+        assert_eq!(value.to_string(), "one, two,\nthree, four,\nfive, six");
+
+        let value = join_wrap(words, " | ", 80);
+        assert_eq!(value.to_string(), "one | two | three | four | five | six");
+
+        let value = join_wrap(["toolong", "x"], " ", 3);
+        assert_eq!(value.to_string(), "toolong\nx");
+
+        let value = join_wrap([""; 0], " ", 3);
+        assert_eq!(value.to_string(), "");
+    }
+
+    #[test]
+    fn join_indent() {
+        let value = join_wrap_indent(["aa", "bb", "cc", "dd"], " ", 5, 4);
+        assert_eq!(value.to_string(), "aa bb\n    cc\n    dd");
+    }
+}